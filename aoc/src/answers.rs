@@ -0,0 +1,100 @@
+use aoc_common::{Answer, Part};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+/// The answers file checked in at the root of the workspace.
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// An answer as written in the file. TOML integers stop at `i64::MAX`, so bigger answers are
+/// written as strings of digits, which are read back as numbers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Recorded> for Answer {
+    fn from(recorded: Recorded) -> Self {
+        match recorded {
+            Recorded::Number(number) => Answer::Number(number.into()),
+            Recorded::Text(text) => match text.parse::<i128>() {
+                Ok(number) => Answer::Number(number),
+                Err(_) => Answer::Text(text),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part_one: Option<Recorded>,
+    part_two: Option<Recorded>,
+}
+
+/// Expected answers keyed by day and part.
+pub struct Answers(BTreeMap<(u8, Part), Answer>);
+
+impl Answers {
+    /// Reads an answers file made of `[day_NN]` tables with `part_one` and `part_two` keys.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+        let tables: BTreeMap<String, DayAnswers> =
+            toml::from_str(&contents).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        let mut answers = BTreeMap::new();
+        for (key, day) in tables {
+            let number = key
+                .strip_prefix("day_")
+                .and_then(|number| number.parse::<u8>().ok())
+                .ok_or(format!(
+                    "{}: expected a table named like `day_01`, found `{}`",
+                    path.display(),
+                    key
+                ))?;
+
+            for (part, recorded) in [(Part::One, day.part_one), (Part::Two, day.part_two)] {
+                if let Some(recorded) = recorded {
+                    answers.insert((number, part), recorded.into());
+                }
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    /// Days that have a recorded answer, in order.
+    pub fn days(&self) -> BTreeSet<u8> {
+        self.0.keys().map(|(day, _)| *day).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_past_i64_can_be_written_as_strings() {
+        let day: DayAnswers =
+            toml::from_str("part_one = \"9999999999600000000004\"\npart_two = \"EHPZPJ\"").unwrap();
+
+        assert_eq!(
+            Answer::from(day.part_one.unwrap()),
+            Answer::Number(9999999999600000000004)
+        );
+        assert_eq!(
+            Answer::from(day.part_two.unwrap()),
+            Answer::Text("EHPZPJ".to_string())
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::Timing;
+use std::time::Duration;
+
+/// Minimum, median and maximum of a set of timings for one stage.
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    /// Summarises `samples`, which must not be empty.
+    pub fn of(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Summary {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Summaries of every stage of one day.
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Summary,
+    pub part_one: Summary,
+    pub part_two: Summary,
+}
+
+impl DayReport {
+    pub fn new(day: u8, timings: &[Timing]) -> Self {
+        DayReport {
+            day,
+            parse: Summary::of(timings.iter().map(|timing| timing.parse).collect()),
+            part_one: Summary::of(timings.iter().map(|timing| timing.part_one).collect()),
+            part_two: Summary::of(timings.iter().map(|timing| timing.part_two).collect()),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Renders the reports as a markdown table with one row per day and stage.
+pub fn markdown(reports: &[DayReport], runs: usize) -> String {
+    let mut table = format!(
+        "Wall time in milliseconds over {} runs.\n\n\
+         | Day | Stage | Min | Median | Max |\n\
+         |----:|:------|----:|-------:|----:|\n",
+        runs
+    );
+
+    for report in reports {
+        for (stage, summary) in [
+            ("parse", &report.parse),
+            ("part one", &report.part_one),
+            ("part two", &report.part_two),
+        ] {
+            table.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                report.day,
+                stage,
+                millis(summary.min),
+                millis(summary.median),
+                millis(summary.max)
+            ));
+        }
+    }
+
+    table
+}
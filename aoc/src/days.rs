@@ -0,0 +1,93 @@
+use aoc_common::{solve, time, Answer, Part, Result, Timing};
+
+/// A single day's solution as seen by the runner.
+pub struct Day {
+    pub number: u8,
+    /// Path to the puzzle input checked in alongside the day's crate.
+    pub input: &'static str,
+    pub solve: fn(&str, Part) -> Result<Answer>,
+    pub time: fn(&str) -> Result<Timing>,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        input: day_01::INPUT,
+        solve: solve::<day_01::Solution>,
+        time: time::<day_01::Solution>,
+    },
+    Day {
+        number: 2,
+        input: day_02::INPUT,
+        solve: solve::<day_02::Solution>,
+        time: time::<day_02::Solution>,
+    },
+    Day {
+        number: 3,
+        input: day_03::INPUT,
+        solve: solve::<day_03::Solution>,
+        time: time::<day_03::Solution>,
+    },
+    Day {
+        number: 4,
+        input: day_04::INPUT,
+        solve: solve::<day_04::Solution>,
+        time: time::<day_04::Solution>,
+    },
+    Day {
+        number: 5,
+        input: day_05::INPUT,
+        solve: solve::<day_05::Solution>,
+        time: time::<day_05::Solution>,
+    },
+    Day {
+        number: 6,
+        input: day_06::INPUT,
+        solve: solve::<day_06::Solution>,
+        time: time::<day_06::Solution>,
+    },
+    Day {
+        number: 7,
+        input: day_07::INPUT,
+        solve: solve::<day_07::Solution>,
+        time: time::<day_07::Solution>,
+    },
+    Day {
+        number: 8,
+        input: day_08::INPUT,
+        solve: solve::<day_08::Solution>,
+        time: time::<day_08::Solution>,
+    },
+    Day {
+        number: 9,
+        input: day_09::INPUT,
+        solve: solve::<day_09::Solution>,
+        time: time::<day_09::Solution>,
+    },
+    Day {
+        number: 10,
+        input: day_10::INPUT,
+        solve: solve::<day_10::Solution>,
+        time: time::<day_10::Solution>,
+    },
+    Day {
+        number: 11,
+        input: day_11::INPUT,
+        solve: solve::<day_11::Solution>,
+        time: time::<day_11::Solution>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// The given day, or every day when `number` is `None`.
+pub fn select(number: Option<u8>) -> std::result::Result<Vec<&'static Day>, String> {
+    match number {
+        Some(number) => Ok(vec![
+            find(number).ok_or(format!("day {} is not solved yet", number))?
+        ]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
@@ -0,0 +1,252 @@
+mod answers;
+mod bench;
+mod days;
+
+use answers::{Answers, ANSWERS};
+use aoc_common::{Answer, Part, Source};
+use bench::DayReport;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+/// Runs the Advent of Code 2023 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with `--all`.
+    Run(RunArgs),
+    /// Check every day against the answers recorded in `answers.toml`.
+    Verify(VerifyArgs),
+    /// Time each day's parse, part one and part two over several runs.
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run.
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Part to run (1 or 2). Runs both parts when omitted.
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Puzzle input to read, or `-` for stdin. Defaults to `$AOC_INPUT` if set, otherwise the
+    /// input file in the day's crate.
+    #[arg(long, conflicts_with = "all")]
+    input: Option<Source>,
+
+    /// Run every day against its own input.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// How to print each answer.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `Day 1, part 1: 54304`
+    Text,
+    /// One JSON object per line: `{"day":1,"part":1,"answer":54304,"elapsed_ms":0.2}`
+    Json,
+}
+
+/// One answer as printed by `--format json`.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: Part,
+    answer: &'a Answer,
+    /// Wall time to parse the input and solve this part.
+    elapsed_ms: f64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Answers file to check against.
+    #[arg(long, default_value = ANSWERS)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Number of times to run each day.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Where to write the markdown table of results.
+    #[arg(long, default_value = "benchmarks.md")]
+    output: PathBuf,
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let selected = days::select(args.day)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    for day in selected {
+        // every day reads its own input with --all, whatever $AOC_INPUT says
+        let source = if args.all {
+            Source::File(PathBuf::from(day.input))
+        } else {
+            Source::resolve(args.input.clone(), day.input)
+        };
+        let input = source.read().map_err(|err| err.to_string())?;
+
+        for part in &parts {
+            let start = Instant::now();
+            let answer = (day.solve)(&input, *part)
+                .map_err(|err| format!("day {}: {}", day.number, err.in_file(source.path())))?;
+            let elapsed = start.elapsed();
+
+            match args.format {
+                Format::Text => println!("Day {}, part {}: {}", day.number, part, answer),
+                Format::Json => {
+                    let record = Record {
+                        day: day.number,
+                        part: *part,
+                        answer: &answer,
+                        elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+                    };
+                    println!(
+                        "{}",
+                        serde_json::to_string(&record).map_err(|err| err.to_string())?
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load(&args.answers)?;
+
+    let selected = days::select(args.day)?;
+
+    for number in answers.days() {
+        if days::find(number).is_none() {
+            return Err(format!(
+                "{} records answers for day {}, which is not solved yet",
+                args.answers.display(),
+                number
+            ));
+        }
+    }
+
+    let (mut passed, mut failed, mut unrecorded) = (0, 0, 0);
+
+    for day in selected {
+        let input = fs::read_to_string(day.input)
+            .map_err(|err| format!("could not read {}: {}", day.input, err))?;
+
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = answers.get(day.number, part) else {
+                println!("Day {}, part {}: no recorded answer", day.number, part);
+                unrecorded += 1;
+                continue;
+            };
+
+            match (day.solve)(&input, part) {
+                Ok(answer) if answer == *expected => {
+                    println!("Day {}, part {}: ok", day.number, part);
+                    passed += 1;
+                }
+                Ok(answer) => {
+                    println!(
+                        "Day {}, part {}: MISMATCH, expected {} but got {}",
+                        day.number, part, expected, answer
+                    );
+                    failed += 1;
+                }
+                Err(err) => {
+                    println!(
+                        "Day {}, part {}: FAILED, {}",
+                        day.number,
+                        part,
+                        err.in_file(Path::new(day.input))
+                    );
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} without a recorded answer",
+        passed, failed, unrecorded
+    );
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} answer(s) did not verify", failed))
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let selected = days::select(args.day)?;
+
+    let mut reports = Vec::new();
+
+    for day in selected {
+        let input = fs::read_to_string(day.input)
+            .map_err(|err| format!("could not read {}: {}", day.input, err))?;
+
+        let timings = (0..args.runs)
+            .map(|_| (day.time)(&input))
+            .collect::<aoc_common::Result<Vec<_>>>()
+            .map_err(|err| format!("day {}: {}", day.number, err.in_file(Path::new(day.input))))?;
+
+        reports.push(DayReport::new(day.number, &timings));
+    }
+
+    let table = bench::markdown(&reports, args.runs as usize);
+    print!("{}", table);
+
+    fs::write(&args.output, table)
+        .map_err(|err| format!("could not write {}: {}", args.output.display(), err))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
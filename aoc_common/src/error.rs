@@ -0,0 +1,114 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong while solving a day's puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input did not have the shape the solver expects.
+    Input(String),
+    /// The puzzle input could not be read from the named file or stream.
+    Read(String, std::io::Error),
+    /// A specific line of the puzzle input could not be parsed.
+    Parse(ParseError),
+    /// A number grew too large for its type; holds what was being calculated.
+    Overflow(&'static str),
+}
+
+impl Error {
+    /// Records which file the input came from, so parse errors can point at it.
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(ParseError {
+                file: Some(path.to_path_buf()),
+                ..err
+            }),
+            err => err,
+        }
+    }
+}
+
+/// Fails with an [`Error::Input`] listing every one of `issues`, one per line, when a
+/// validator found any. `subject` names what was checked, e.g. "the maze".
+pub fn reject_issues<T: fmt::Display>(subject: &str, issues: &[T]) -> Result<()> {
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    let report: Vec<String> = issues.iter().map(T::to_string).collect();
+    Err(Error::Input(format!(
+        "{} has {} problem(s):\n  {}",
+        subject,
+        issues.len(),
+        report.join("\n  ")
+    )))
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(message) => write!(f, "invalid input: {}", message),
+            Error::Read(source, err) => write!(f, "could not read {}: {}", source, err),
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::Overflow(what) => write!(f, "{} overflowed", what),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// What was wrong with the text a [`ParseError`] points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The text should have been a number.
+    InvalidNumber,
+    /// The text was not what the parser expected; holds a description of what it wanted.
+    Expected(&'static str),
+    /// A row of a grid did not match the width of the rows before it.
+    RowWidth(usize),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::Expected(what) => write!(f, "expected {}", what),
+            ParseErrorKind::RowWidth(width) => write!(f, "expected a row {} cells wide", width),
+        }
+    }
+}
+
+/// A parse failure, located by 1-based line and column in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input file, when known. Solvers parse strings, so this is filled in by whoever read
+    /// the file (see [`Error::in_file`]).
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    /// The offending text. Empty when the parser ran off the end of the line.
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file.display(), self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+
+        if self.text.is_empty() {
+            write!(f, "{}, found end of line", self.kind)
+        } else {
+            write!(f, "{}, found `{}`", self.kind, self.text)
+        }
+    }
+}
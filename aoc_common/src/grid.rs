@@ -0,0 +1,279 @@
+use crate::error::ParseErrorKind;
+use crate::offsets::{ALL, ORTHOGONAL};
+use crate::parse::lines;
+use crate::Result;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A (row, col) position in a [`Grid`].
+pub type Position = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `cell` for every position, row by row.
+    pub fn from_fn(height: usize, width: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut cell)
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line of `input` and one cell per character, turning each character
+    /// into a cell with `cell`. Characters it rejects are reported as parse errors expecting
+    /// `expected`, as are rows that are not as wide as the first one.
+    pub fn parse(
+        input: &str,
+        expected: &'static str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines(input) {
+            let row_start = cells.len();
+
+            for (i, c) in line.text.char_indices() {
+                let parsed = cell(c).ok_or_else(|| {
+                    line.error(
+                        &line.text[i..i + c.len_utf8()],
+                        ParseErrorKind::Expected(expected),
+                    )
+                })?;
+                cells.push(parsed);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(line.text, ParseErrorKind::RowWidth(width)));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by a (row, col) offset, or `None` if that leaves the grid.
+    pub fn offset(
+        &self,
+        (row, col): Position,
+        (row_offset, col_offset): (i32, i32),
+    ) -> Option<Position> {
+        let row = row.checked_add_signed(row_offset as isize)?;
+        let col = col.checked_add_signed(col_offset as isize)?;
+
+        (row < self.height && col < self.width).then_some((row, col))
+    }
+
+    /// The in-bounds positions above, below, left and right of `position`, in that order.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    /// The in-bounds positions of all eight neighbours of `position`, diagonals included,
+    /// from the top-left one to the bottom-right one.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL.iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn indexed(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Builds a grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Every cell, row by row.
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+/// Prints one line per row, with the cells written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = digits();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 2)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn transposes_and_prints() {
+        let transposed = digits().transpose();
+        assert_eq!((transposed.height(), transposed.width()), (3, 2));
+        assert_eq!(transposed.to_string(), "14\n25\n36\n");
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let bad_cell = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10));
+        assert!(matches!(bad_cell, Err(Error::Parse(err)) if (err.line, err.column) == (2, 2)));
+
+        let ragged = Grid::parse("12\n345", "a digit", |c| c.to_digit(10));
+        assert!(matches!(
+            ragged,
+            Err(Error::Parse(err)) if err.kind == ParseErrorKind::RowWidth(2)
+        ));
+    }
+}
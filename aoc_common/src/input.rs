@@ -0,0 +1,81 @@
+use crate::{Error, Result};
+use std::convert::Infallible;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable read for the puzzle input when none is given on the command line.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where to read a puzzle input from. Parsed from a command-line argument, where `-` means
+/// standard input and anything else is a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the input to read: `arg` if given, otherwise `$AOC_INPUT` if it is set and not
+    /// empty, otherwise the `default` path.
+    pub fn resolve(arg: Option<Source>, default: &str) -> Self {
+        arg.or_else(|| {
+            std::env::var(INPUT_VAR)
+                .ok()
+                .filter(|value| !value.is_empty())
+                .map(Source::from)
+        })
+        .unwrap_or_else(|| Source::File(PathBuf::from(default)))
+    }
+
+    /// Resolves the input for a day's own binary from its first command-line argument.
+    pub fn from_args(default: &str) -> Self {
+        Source::resolve(std::env::args().nth(1).map(Source::from), default)
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let read = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        };
+
+        read.map_err(|err| Error::Read(self.to_string(), err))
+    }
+
+    /// The path parse errors should point at; `<stdin>` when reading standard input.
+    pub fn path(&self) -> &Path {
+        match self {
+            Source::Stdin => Path::new("<stdin>"),
+            Source::File(path) => path,
+        }
+    }
+}
+
+impl From<String> for Source {
+    fn from(arg: String) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Source::from(s.to_string()))
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path().display())
+    }
+}
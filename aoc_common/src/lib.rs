@@ -0,0 +1,13 @@
+//! Helpers shared by every day of the Advent of Code 2023 solutions.
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod offsets;
+pub mod parse;
+pub mod solver;
+
+pub use error::{reject_issues, Error, ParseError, ParseErrorKind, Result};
+pub use grid::{Grid, Position};
+pub use input::Source;
+pub use solver::{print_answers, run_day, solve, time, Answer, IntoAnswer, Part, Solver, Timing};
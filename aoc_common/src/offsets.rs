@@ -0,0 +1,21 @@
+/// (row, col) offsets to the four orthogonal neighbours of a cell, in the order above, below,
+/// left, right.
+pub const ORTHOGONAL: &[(i32, i32)] = &[
+    (-1, 0), // above
+    (1, 0),  // below
+    (0, -1), // left
+    (0, 1),  // right
+];
+
+/// (row, col) offsets to all eight neighbours of a cell, including the diagonals. Ordered
+/// row by row, from the top-left neighbour to the bottom-right one.
+pub const ALL: &[(i32, i32)] = &[
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
@@ -0,0 +1,108 @@
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::Result;
+use std::str::FromStr;
+
+/// One line of puzzle input, along with its 1-based line number so that parse errors can
+/// point back into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Builds an error pointing at `at`, which should be a slice of this line's text. Anything
+    /// else is reported at the start of the line.
+    pub fn error(&self, at: &str, kind: ParseErrorKind) -> Error {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize).wrapping_sub(start);
+        let column = if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Error::Parse(ParseError {
+            file: None,
+            line: self.number,
+            column,
+            text: at.to_string(),
+            kind,
+        })
+    }
+
+    /// Builds an error pointing just past the end of this line.
+    pub fn error_at_end(&self, kind: ParseErrorKind) -> Error {
+        self.error(&self.text[self.text.len()..], kind)
+    }
+}
+
+/// Splits `input` into numbered lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+/// Parses a single number, reporting `token`'s position within `line` if it is not one.
+pub fn parse_number<T: FromStr>(line: &Line, token: &str) -> Result<T> {
+    token
+        .parse::<T>()
+        .map_err(|_| line.error(token, ParseErrorKind::InvalidNumber))
+}
+
+/// Parses a whitespace-separated list of numbers, e.g. `"79 14 55 13"`, where `numbers` is a
+/// slice of `line`'s text.
+pub fn parse_numbers<T: FromStr>(line: &Line, numbers: &str) -> Result<Vec<T>> {
+    numbers
+        .split_whitespace()
+        .map(|digit_str| parse_number(line, digit_str))
+        .collect()
+}
+
+/// Returns the text after the first `delimiter` in `line`, or an error pointing at the end of
+/// the line if there isn't one.
+pub fn after<'a>(line: &Line<'a>, delimiter: char, expected: &'static str) -> Result<&'a str> {
+    match line.text.find(delimiter) {
+        Some(index) => Ok(&line.text[index + delimiter.len_utf8()..]),
+        None => Err(line.error_at_end(ParseErrorKind::Expected(expected))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(err: Error) -> ParseError {
+        match err {
+            Error::Parse(err) => err,
+            err => panic!("expected a parse error, got {}", err),
+        }
+    }
+
+    #[test]
+    fn numbers_are_located_by_column() {
+        let line = lines("one\nseeds: 79 x4 55").nth(1).unwrap();
+        let numbers = after(&line, ':', "`:`").unwrap();
+
+        let err = parse_error(parse_numbers::<u32>(&line, numbers).unwrap_err());
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "x4"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 11: invalid number, found `x4`"
+        );
+    }
+
+    #[test]
+    fn missing_delimiter_points_past_the_end() {
+        let line = lines("Game 1").next().unwrap();
+
+        let err = parse_error(after(&line, ':', "`:`").unwrap_err());
+        assert_eq!(err.column, 7);
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: expected `:`, found end of line"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::input::Source;
+use crate::Result;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got `{}`", s)),
+        }
+    }
+}
+
+/// Serialized as the number of the part, like its [`Display`](fmt::Display).
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+/// The answer to one part of a puzzle. Every day's typed answer converts into this so that
+/// answers from different days can be compared, stored and printed the same way. Serializes
+/// as a bare number or string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_number_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_answer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// What a part returns: an answer, or a `Result` of one for parts that can fail, e.g. because
+/// their arithmetic overflows.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<Answer> {
+        self.map(Into::into)
+    }
+}
+
+/// A day's solution: parse the puzzle input once, then answer either part from it.
+pub trait Solver {
+    type Input;
+    type PartOne: IntoAnswer;
+    type PartTwo: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// Parses `input` and answers `part` of it with `S`.
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<Answer> {
+    let parsed = S::parse(input)?;
+
+    match part {
+        Part::One => S::part_one(&parsed).into_answer(),
+        Part::Two => S::part_two(&parsed).into_answer(),
+    }
+}
+
+/// Wall time taken by each stage of a single run of a day.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// Parses `input` and answers both parts with `S`, timing each stage separately.
+pub fn time<S: Solver>(input: &str) -> Result<Timing> {
+    let start = Instant::now();
+    let parsed = black_box(S::parse(input)?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&parsed).into_answer()?);
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_two(&parsed).into_answer()?);
+    let part_two = start.elapsed();
+
+    Ok(Timing {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// Entry point for a day's own binary: reads the input named by the first argument, `-` for
+/// stdin, falling back to `$AOC_INPUT` and then to `default`, and prints both parts.
+pub fn run_day<S: Solver>(default: &str) -> ExitCode {
+    let source = Source::from_args(default);
+
+    match source.read() {
+        Ok(input) => print_answers::<S>(&input, source.path()),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses `input`, which was read from `path`, and prints both parts.
+pub fn print_answers<S: Solver>(input: &str, path: &Path) -> ExitCode {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: {}", err.in_file(path));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for (name, answer) in [
+        ("Part one", S::part_one(&parsed).into_answer()),
+        ("Part two", S::part_two(&parsed).into_answer()),
+    ] {
+        match answer {
+            Ok(answer) => println!("{}: {}", name, answer),
+            Err(err) => {
+                eprintln!("error: {}: {}", name.to_lowercase(), err);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_serialize_as_bare_values() {
+        assert_eq!(serde_json::to_string(&Answer::from(42u64)).unwrap(), "42");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("XYZ")).unwrap(),
+            "\"XYZ\""
+        );
+        assert_eq!(serde_json::to_string(&Part::Two).unwrap(), "2");
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::parse::lines;
+use aoc_common::{ParseErrorKind, Result, Solver};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/calibration.txt");
+
+const DIGIT_WORDS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn part_one(calibration_values: &[String]) -> i32 {
+    calibration_values
+        .iter()
+        .map(|line| {
+            let mut first: Option<char> = None;
+            let mut prev: Option<char> = None;
+            for char in line.chars() {
+                if char.is_ascii_digit() {
+                    if first.is_none() {
+                        first = Some(char)
+                    }
+
+                    prev = Some(char);
+                }
+            }
+
+            match (first, prev) {
+                (Some(first), Some(prev)) => format!("{}{}", first, prev).parse::<i32>().unwrap(),
+                // the digits on this line are all spelled out, which only counts in part two.
+                _ => 0,
+            }
+        })
+        .sum::<i32>()
+}
+
+fn part_two(calibration_values: &[String]) -> i32 {
+    use std::collections::HashMap;
+
+    let string_to_digit: HashMap<&str, char> = DIGIT_WORDS.iter().copied().zip('1'..='9').collect();
+
+    calibration_values
+        .iter()
+        .map(|line| {
+            let mut first: Option<char> = None;
+            let mut prev: Option<char> = None;
+
+            for (i, char) in line.char_indices() {
+                if char.is_ascii_digit() {
+                    if first.is_none() {
+                        first = Some(char)
+                    }
+                    prev = Some(char);
+                } else {
+                    for key in string_to_digit.keys() {
+                        // for each char, check if it's a key. since the only relevant letters
+                        // are `o`, `t`, `f`, `s`, `e`, `n`, can optimise by ignoring all other chars.
+                        if line[i..].starts_with(key) {
+                            if first.is_none() {
+                                first = Some(*string_to_digit.get(key).unwrap())
+                            }
+
+                            prev = Some(*string_to_digit.get(key).unwrap());
+                        }
+                    }
+                }
+            }
+
+            let parsed_digits = format!("{}{}", first.unwrap(), prev.unwrap())
+                .parse::<i32>()
+                .unwrap();
+
+            parsed_digits
+        })
+        .sum::<i32>()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input)
+            .map(|line| {
+                let has_digit = line.text.chars().any(|c| c.is_ascii_digit())
+                    || DIGIT_WORDS.iter().any(|word| line.text.contains(word));
+
+                if has_digit {
+                    Ok(line.text.to_string())
+                } else {
+                    Err(line.error(
+                        line.text,
+                        ParseErrorKind::Expected("a line containing a digit"),
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(calibration_values: &Self::Input) -> i32 {
+        part_one(calibration_values)
+    }
+
+    fn part_two(calibration_values: &Self::Input) -> i32 {
+        part_two(calibration_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("../example_part_two.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), 142);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Solution::parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Solution::part_two(&input), 281);
+    }
+
+    #[test]
+    fn overlapping_digit_words() {
+        // the last digit of "xtwone" is one, not two
+        assert_eq!(part_two(&["xtwone".to_string()]), 21);
+        assert_eq!(part_two(&["eighthree".to_string()]), 83);
+    }
+
+    #[test]
+    fn line_without_digits_is_rejected() {
+        assert!(Solution::parse("1abc2\nabc").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_common::parse::{after, lines, parse_number, Line};
+use aoc_common::{ParseErrorKind, Result, Solver};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/cubes.txt");
+
+/// A game's id, and the count of each colour of cube revealed in each of its sets.
+pub type Game = (u32, Vec<HashMap<String, u32>>);
+
+const COLOURS: &[&str] = &["red", "green", "blue"];
+
+/* PART ONE: Determine which games would have been possible if the bag had been loaded
+with only 12 red cubes, 13 green cubes, and 14 blue cubes. What is the sum of
+ the IDs of those games? */
+fn parse_cube_line(line: &Line) -> Result<Game> {
+    let re = Regex::new(r"Game (?P<game>[0-9]+):").unwrap();
+    let parsed_regex = re
+        .captures(line.text)
+        .ok_or_else(|| line.error(line.text, ParseErrorKind::Expected("`Game <id>:`")))?;
+
+    let game: u32 = parse_number(line, parsed_regex.name("game").unwrap().as_str())?;
+    let cube_sets: Vec<HashMap<String, u32>> = parse_games(line, after(line, ':', "`:`")?)?;
+
+    Ok((game, cube_sets))
+}
+
+fn parse_games(line: &Line, games: &str) -> Result<Vec<HashMap<String, u32>>> {
+    games
+        .trim()
+        .split(';')
+        .filter(|segment| !segment.trim().is_empty())
+        .map(|game_set| {
+            let mut colour_counts: HashMap<String, u32> = HashMap::new();
+
+            for set in game_set.trim().split(',') {
+                let parts: Vec<&str> = set.split_whitespace().collect();
+                match parts.as_slice() {
+                    [count, colour] if COLOURS.contains(colour) => {
+                        colour_counts.insert(colour.to_string(), parse_number(line, count)?);
+                    }
+                    [_, colour] => {
+                        return Err(line
+                            .error(colour, ParseErrorKind::Expected("`red`, `green` or `blue`")))
+                    }
+                    _ => {
+                        return Err(line.error(
+                            set.trim(),
+                            ParseErrorKind::Expected("a count followed by a colour"),
+                        ))
+                    }
+                }
+            }
+
+            Ok(colour_counts)
+        })
+        .collect()
+}
+
+fn part_one(games: &[Game]) -> u32 {
+    let mut requirements: HashMap<&str, u32> = HashMap::new();
+    requirements.insert("red", 12);
+    requirements.insert("green", 13);
+    requirements.insert("blue", 14);
+
+    games
+        .iter()
+        .filter(|(_game, sets)| {
+            for set in sets {
+                let result = &requirements
+                    .keys()
+                    .all(|key| requirements.get(key).unwrap() >= set.get(*key).unwrap_or(&0_u32));
+                if !*result {
+                    return *result;
+                }
+            }
+
+            true
+        })
+        .fold(0_u32, |acc, val| acc + val.0)
+}
+
+fn part_two(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|(_, sets)| {
+            let mut red_max = &u32::MIN;
+            let mut green_max = &u32::MIN;
+            let mut blue_max = &u32::MIN;
+
+            for set in sets {
+                if set.get("red").unwrap_or(&0) > red_max {
+                    red_max = set.get("red").unwrap_or(&0);
+                }
+                if set.get("green").unwrap_or(&0) > green_max {
+                    green_max = set.get("green").unwrap_or(&0);
+                }
+                if set.get("blue").unwrap_or(&0) > blue_max {
+                    blue_max = set.get("blue").unwrap_or(&0);
+                }
+            }
+
+            red_max * blue_max * green_max
+        })
+        .sum::<u32>()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        lines(input).map(|line| parse_cube_line(&line)).collect()
+    }
+
+    fn part_one(games: &Self::Input) -> u32 {
+        part_one(games)
+    }
+
+    fn part_two(games: &Self::Input) -> u32 {
+        part_two(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Error;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), 8);
+        assert_eq!(Solution::part_two(&input), 2286);
+    }
+
+    #[test]
+    fn unknown_colour_is_located() {
+        match Solution::parse("Game 1: 3 blue\nGame 2: 3 bleu") {
+            Err(Error::Parse(err)) => {
+                assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "bleu"));
+            }
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use aoc_common::{Error, Grid, ParseError, ParseErrorKind, Position, Result, Solver};
+use std::cmp::{Ord, Ordering};
+use std::collections::HashSet;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/schematics.txt");
+
+fn get_digit(
+    position: Position,
+    movement: i32,
+    matrix: &Grid<char>,
+    visited: &mut HashSet<Position>,
+) -> String {
+    if visited.contains(&position) {
+        return "".to_string();
+    };
+
+    let char = matrix[position];
+
+    if char.is_ascii_digit() {
+        visited.insert(position);
+
+        // keep reading the number in the given direction, stopping at the edge of the grid
+        let mut next_digit = |movement: i32| match matrix.offset(position, (0, movement)) {
+            Some(next) => get_digit(next, movement, matrix, visited),
+            None => "".to_string(),
+        };
+
+        match movement.cmp(&0) {
+            Ordering::Equal => {
+                let left = next_digit(-1);
+                let right = next_digit(1);
+
+                return format!("{}{}{}", left, char, right);
+            }
+            Ordering::Less => {
+                let left = next_digit(movement);
+                return format!("{left}{char}");
+            }
+            Ordering::Greater => {
+                let right = next_digit(movement);
+                return format!("{char}{right}");
+            }
+        };
+    }
+
+    "".to_string()
+}
+
+/// Reads the number whose digits run through `position`, reporting where it starts in the
+/// schematic if it doesn't fit in a `u64`.
+fn read_number(digits: &str, position: Position, matrix: &Grid<char>) -> Result<u64> {
+    let mut start = position;
+    while let Some(left) = matrix
+        .offset(start, (0, -1))
+        .filter(|left| matrix[*left].is_ascii_digit())
+    {
+        start = left;
+    }
+
+    digits.parse::<u64>().map_err(|_| {
+        Error::Parse(ParseError {
+            file: None,
+            line: start.0 + 1,
+            column: start.1 + 1,
+            text: digits.to_string(),
+            kind: ParseErrorKind::InvalidNumber,
+        })
+    })
+}
+
+fn adjacent_numbers(
+    position: Position,
+    matrix: &Grid<char>,
+    visited: &mut HashSet<Position>,
+) -> Result<Vec<u64>> {
+    matrix
+        .neighbours8(position)
+        .map(|neighbour| {
+            // numbers directly to the left or right are only read away from the symbol
+            let movement = if neighbour.0 == position.0 {
+                neighbour.1 as i32 - position.1 as i32
+            } else {
+                0
+            };
+
+            (neighbour, get_digit(neighbour, movement, matrix, visited))
+        })
+        .filter(|(_, digit_str)| !digit_str.is_empty())
+        .map(|(neighbour, digit_str)| read_number(&digit_str, neighbour, matrix))
+        .collect()
+}
+
+fn part_one(matrix: &Grid<char>) -> Result<u64> {
+    let mut visited = HashSet::new();
+    let mut total_parts: u64 = 0;
+
+    for (position, char) in matrix.indexed() {
+        if !char.is_ascii_digit() && *char != '.' {
+            for number in adjacent_numbers(position, matrix, &mut visited)? {
+                total_parts = total_parts
+                    .checked_add(number)
+                    .ok_or(Error::Overflow("the sum of the part numbers"))?;
+            }
+        }
+    }
+    Ok(total_parts)
+}
+
+fn part_two(matrix: &Grid<char>) -> Result<u64> {
+    let mut visited = HashSet::new();
+    let mut total_gears: u64 = 0;
+
+    for (position, char) in matrix.indexed() {
+        if *char == '*' {
+            let adjacent_numbers = adjacent_numbers(position, matrix, &mut visited)?;
+
+            // magic number exactly 2, as given by the problem definition. gear ratio when exactly
+            // two adjacent numbers beside a `*`
+            if let [first, second] = adjacent_numbers.as_slice() {
+                total_gears = first
+                    .checked_mul(*second)
+                    .and_then(|ratio| total_gears.checked_add(ratio))
+                    .ok_or(Error::Overflow("the sum of the gear ratios"))?;
+            }
+        }
+    }
+    Ok(total_gears)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Grid<char>;
+    type PartOne = Result<u64>;
+    type PartTwo = Result<u64>;
+
+    fn parse(schematics_text: &str) -> Result<Self::Input> {
+        Grid::parse(schematics_text, "a character", Some)
+    }
+
+    fn part_one(matrix: &Self::Input) -> Result<u64> {
+        part_one(matrix)
+    }
+
+    fn part_two(matrix: &Self::Input) -> Result<u64> {
+        part_two(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 4361);
+        assert_eq!(Solution::part_two(&input).unwrap(), 467835);
+    }
+
+    #[test]
+    fn numbers_touching_the_edges() {
+        // symbols and numbers in the first and last rows and columns
+        let input = Solution::parse("12*\n...\n#45").unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 57);
+
+        let input = Solution::parse("2*3\n...").unwrap();
+        assert_eq!(Solution::part_two(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn numbers_too_big_to_read_are_located() {
+        let input = Solution::parse("......................\n.*99999999999999999999").unwrap();
+        assert!(matches!(
+            Solution::part_one(&input),
+            Err(Error::Parse(err)) if (err.line, err.column) == (2, 3)
+        ));
+
+        // each number fits, but their gear ratio doesn't
+        let input = Solution::parse("9999999999*9999999999").unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 19999999998);
+        assert!(matches!(
+            Solution::part_two(&input),
+            Err(Error::Overflow(_))
+        ));
+    }
+}
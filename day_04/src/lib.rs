@@ -0,0 +1,133 @@
+use aoc_common::parse::{after, lines, parse_numbers, Line};
+use aoc_common::{Error, ParseErrorKind, Result, Solver};
+use std::collections::HashSet;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tickets.txt");
+
+/// The winning numbers and the numbers we have, in that order.
+pub type Ticket = (Vec<u32>, Vec<u32>);
+
+fn parse_ticket(ticket: &Line) -> Result<Ticket> {
+    parse_winnings(ticket, after(ticket, ':', "`:` after the card number")?)
+}
+
+fn parse_winnings(ticket: &Line, numbers: &str) -> Result<Ticket> {
+    match numbers.split_once('|') {
+        Some((winning, ours)) => Ok((
+            parse_numbers(ticket, winning)?,
+            parse_numbers(ticket, ours)?,
+        )),
+        None => Err(ticket.error_at_end(ParseErrorKind::Expected(
+            "`|` between the winning numbers and ours",
+        ))),
+    }
+}
+
+fn part_one(tickets: &[Ticket]) -> Result<u64> {
+    get_matching_wins(tickets)
+        .iter()
+        .map(|num_wins| match num_wins {
+            0 => Some(0),
+            // 2^(n-1)
+            _ => u32::try_from(num_wins - 1)
+                .ok()
+                .and_then(|power| 1_u64.checked_shl(power)),
+        })
+        .try_fold(0_u64, |total, points| total.checked_add(points?))
+        .ok_or(Error::Overflow("the points won"))
+}
+
+fn part_two(tickets: &[Ticket]) -> Result<u64> {
+    // think of each ticket as a bucket. each bucket can have multiple copies
+    // of itself. e.g. if the i'th ticket has x matches, then all subsequent
+    // (i + 1) to (i + x) tickets will get one additional copy and so on.
+    // so this problem is just a matter of updating the buckets.
+    let mut bucket_ticket_count: Vec<u64> = vec![1; tickets.len()];
+    let matching_wins_per_ticket = get_matching_wins(tickets);
+    let overflow = || Error::Overflow("the number of scratchcards");
+
+    for i in 0..bucket_ticket_count.len() {
+        // copies never go past the last ticket
+        let last = (i + matching_wins_per_ticket[i]).min(bucket_ticket_count.len() - 1);
+        for j in i + 1..=last {
+            bucket_ticket_count[j] = bucket_ticket_count[j]
+                .checked_add(bucket_ticket_count[i])
+                .ok_or_else(overflow)?;
+        }
+    }
+
+    bucket_ticket_count
+        .into_iter()
+        .try_fold(0_u64, u64::checked_add)
+        .ok_or_else(overflow)
+}
+
+fn get_matching_wins(tickets: &[Ticket]) -> Vec<usize> {
+    tickets
+        .iter()
+        .map(|(winning, ticket)| {
+            let winning: HashSet<&u32> = HashSet::from_iter(winning);
+
+            ticket.iter().filter(|num| winning.contains(num)).count()
+        })
+        .collect()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Ticket>;
+    type PartOne = Result<u64>;
+    type PartTwo = Result<u64>;
+
+    fn parse(tickets: &str) -> Result<Self::Input> {
+        lines(tickets).map(|ticket| parse_ticket(&ticket)).collect()
+    }
+
+    fn part_one(tickets: &Self::Input) -> Result<u64> {
+        part_one(tickets)
+    }
+
+    fn part_two(tickets: &Self::Input) -> Result<u64> {
+        part_two(tickets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 13);
+        assert_eq!(Solution::part_two(&input).unwrap(), 30);
+    }
+
+    #[test]
+    fn card_without_matches_scores_nothing() {
+        let input = Solution::parse("Card 1: 1 2 | 3 4").unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 0);
+        assert_eq!(Solution::part_two(&input).unwrap(), 1);
+    }
+
+    #[test]
+    fn too_many_matches_overflow() {
+        let numbers: Vec<String> = (1..=65).map(|n| n.to_string()).collect();
+        let card = format!("Card 1: {0} | {0}", numbers.join(" "));
+        let input = Solution::parse(&card).unwrap();
+        assert!(matches!(
+            Solution::part_one(&input),
+            Err(Error::Overflow(_))
+        ));
+        // the copies it wins would be past the last card
+        assert_eq!(Solution::part_two(&input).unwrap(), 1);
+
+        // 64 matches is worth 2^63, which still fits
+        let card = format!("Card 1: {0} | {0}", numbers[..64].join(" "));
+        let input = Solution::parse(&card).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 1 << 63);
+    }
+}
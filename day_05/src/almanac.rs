@@ -0,0 +1,281 @@
+use crate::PiecewiseMap;
+use aoc_common::parse::{after, lines, parse_numbers, Line};
+use aoc_common::{Error, ParseErrorKind, Result};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// One `X-to-Y map:` block: the `[dest, source, range]` rows converting category X into Y.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryMap {
+    source: String,
+    destination: String,
+    rows: Vec<Vec<usize>>,
+    /// The line of the map's header, and of each row, for reporting problems with them.
+    header_line: usize,
+    row_lines: Vec<usize>,
+}
+
+impl CategoryMap {
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    pub fn rows(&self) -> &[Vec<usize>] {
+        &self.rows
+    }
+
+    pub fn to_piecewise(&self) -> PiecewiseMap {
+        PiecewiseMap::from_rows(&self.rows)
+    }
+
+    /// Every problem with this map's rows. The lookups only ever use the first row covering a
+    /// number and skip rows that aren't three numbers long, so without this a bad row gives a
+    /// plausible but wrong answer.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut checked_rows = Vec::new();
+
+        for (row, line) in self.rows.iter().zip(&self.row_lines) {
+            let kind = match row.as_slice() {
+                [dest, source, range] => {
+                    let fits = |start: usize| {
+                        start
+                            .checked_add(*range)
+                            .is_some_and(|end| end <= isize::MAX as usize)
+                    };
+
+                    if *range == 0 {
+                        Some(IssueKind::ZeroLength)
+                    } else if !fits(*dest) || !fits(*source) {
+                        Some(IssueKind::Overflow)
+                    } else {
+                        checked_rows.push((*source, source + range, *line));
+                        None
+                    }
+                }
+                numbers => Some(IssueKind::MalformedRow(numbers.len())),
+            };
+
+            if let Some(kind) = kind {
+                issues.push(self.issue(*line, kind));
+            }
+        }
+
+        // after sorting by start, a row overlaps an earlier one exactly when it starts before
+        // the furthest end seen so far
+        checked_rows.sort();
+        let mut furthest: Option<(usize, usize)> = None;
+        for (start, end, line) in checked_rows {
+            match furthest {
+                Some((furthest_end, furthest_line)) if start < furthest_end => {
+                    issues.push(self.issue(line, IssueKind::Overlap(furthest_line)));
+                }
+                _ => {}
+            }
+            if furthest.is_none_or(|(furthest_end, _)| end > furthest_end) {
+                furthest = Some((end, line));
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.line);
+        issues
+    }
+
+    fn issue(&self, line: usize, kind: IssueKind) -> Issue {
+        Issue {
+            line,
+            map: format!("{}-to-{}", self.source, self.destination),
+            kind,
+        }
+    }
+}
+
+/// What is wrong with an almanac map or one of its rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The row has this many numbers instead of `dest source range`.
+    MalformedRow(usize),
+    /// The row's range is empty, so it never maps anything.
+    ZeroLength,
+    /// The end of the row's source or destination range does not fit in a number.
+    Overflow,
+    /// The row's source range overlaps the one of the row on this line.
+    Overlap(usize),
+    /// The map converts the same categories as the one whose header is on this line.
+    DuplicateMap(usize),
+}
+
+/// A problem with an almanac map or one of its rows, found by [`Almanac::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: usize,
+    /// The map the row belongs to, e.g. `seed-to-soil`.
+    pub map: String,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} ({} map): ", self.line, self.map)?;
+
+        match self.kind {
+            IssueKind::MalformedRow(numbers) => write!(
+                f,
+                "expected 3 numbers (destination, source, length), found {}",
+                numbers
+            ),
+            IssueKind::ZeroLength => write!(f, "the range is empty"),
+            IssueKind::Overflow => write!(f, "the range runs past the largest number"),
+            IssueKind::Overlap(line) => {
+                write!(f, "the source range overlaps the one on line {}", line)
+            }
+            IssueKind::DuplicateMap(line) => {
+                write!(f, "the map repeats the one headed on line {}", line)
+            }
+        }
+    }
+}
+
+/// The seeds to plant and a graph of category maps, in the order their headers appear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    /// Reads an almanac without checking its maps; see [`Almanac::validate`].
+    pub fn parse(content: &str) -> Result<Self> {
+        parse_content(content)
+    }
+
+    /// Every problem with every map, in line order. A second map between the same two
+    /// categories is a problem too: the chains only ever use the first, so the other's rows
+    /// would silently never apply.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues: Vec<Issue> = self.maps.iter().flat_map(CategoryMap::validate).collect();
+
+        for (i, map) in self.maps.iter().enumerate() {
+            if let Some(first) = self.maps[..i].iter().find(|earlier| {
+                (earlier.source(), earlier.destination()) == (map.source(), map.destination())
+            }) {
+                issues.push(map.issue(map.header_line, IssueKind::DuplicateMap(first.header_line)));
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.line);
+        issues
+    }
+
+    pub fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    pub fn maps(&self) -> &[CategoryMap] {
+        &self.maps
+    }
+
+    /// The shortest chain of maps converting category `from` into category `to`, found by a
+    /// breadth-first search over the headers. Empty when `from` and `to` are the same.
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap>> {
+        // the map used to first reach each category
+        let mut reached_by: HashMap<&str, Option<&CategoryMap>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut chain = Vec::new();
+                let mut current = to;
+                while let Some(Some(map)) = reached_by.get(current) {
+                    chain.push(*map);
+                    current = map.source();
+                }
+                chain.reverse();
+
+                return Ok(chain);
+            }
+
+            for map in self.maps.iter().filter(|map| map.source == category) {
+                if !reached_by.contains_key(map.destination()) {
+                    reached_by.insert(map.destination(), Some(map));
+                    queue.push_back(map.destination());
+                }
+            }
+        }
+
+        Err(Error::Input(format!(
+            "no chain of maps converts `{}` into `{}`",
+            from, to
+        )))
+    }
+
+    /// The chain from `from` to `to` folded into a single map.
+    pub fn converter(&self, from: &str, to: &str) -> Result<PiecewiseMap> {
+        Ok(self
+            .chain(from, to)?
+            .iter()
+            .fold(PiecewiseMap::identity(), |composed, map| {
+                composed.then(&map.to_piecewise())
+            }))
+    }
+
+    /// Converts `value` of category `from` into category `to`, e.g. a soil number into a
+    /// humidity.
+    pub fn convert(&self, from: &str, to: &str, value: usize) -> Result<usize> {
+        Ok(self.converter(from, to)?.get(value))
+    }
+}
+
+/// Reads `X-to-Y map:` into its two categories.
+fn parse_header<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str)> {
+    line.text
+        .strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+        .ok_or_else(|| {
+            line.error(
+                line.text,
+                ParseErrorKind::Expected("an `X-to-Y map:` header"),
+            )
+        })
+}
+
+pub(crate) fn parse_content(content: &str) -> Result<Almanac> {
+    let mut seeds: Vec<usize> = Vec::new();
+    let mut maps: Vec<CategoryMap> = Vec::new();
+    for line in lines(content) {
+        if line.text.starts_with("seeds:") {
+            seeds = parse_numbers(&line, after(&line, ':', "`:`")?)?;
+        } else if line.text.ends_with("map:") {
+            let (source, destination) = parse_header(&line)?;
+            maps.push(CategoryMap {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                rows: Vec::new(),
+                header_line: line.number,
+                row_lines: Vec::new(),
+            });
+        } else if !line.text.is_empty() {
+            let map = maps.last_mut().ok_or_else(|| {
+                line.error(
+                    line.text,
+                    ParseErrorKind::Expected("an `X-to-Y map:` header"),
+                )
+            })?;
+            map.rows.push(parse_numbers(&line, line.text)?);
+            map.row_lines.push(line.number);
+        }
+    }
+
+    if seeds.is_empty() {
+        return Err(Error::Input(
+            "no seeds listed on a `seeds:` line".to_string(),
+        ));
+    }
+
+    Ok(Almanac { seeds, maps })
+}
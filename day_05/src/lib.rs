@@ -0,0 +1,444 @@
+use aoc_common::{reject_issues, Error, Result, Solver};
+use indicatif::ProgressIterator;
+
+mod almanac;
+mod piecewise;
+
+use almanac::parse_content;
+pub use almanac::{Almanac, CategoryMap, Issue, IssueKind};
+use piecewise::cut;
+pub use piecewise::{PiecewiseMap, Range, Segment};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/seed_maps.txt");
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+fn get_min_location(seeds: &[usize], chain: &[&CategoryMap]) -> usize {
+    seeds
+        .iter()
+        .progress()
+        .map(|seed| {
+            let mut base = *seed;
+            let mut future_base = None;
+
+            for map in chain {
+                for vec in map.rows() {
+                    if let [dest, source, range] = vec.as_slice() {
+                        if base >= *source && base < source + range {
+                            future_base = Some(dest + (base - source));
+                            break;
+                        }
+                    }
+                }
+
+                if let Some(new_base) = future_base {
+                    base = new_base;
+                }
+            }
+
+            base
+        })
+        .min()
+        .unwrap()
+}
+
+fn part_one(seeds: &[usize], chain: &[&CategoryMap]) -> usize {
+    get_min_location(seeds, chain)
+}
+
+fn map_ranges(ranges: Vec<(usize, usize)>, rows: &[Vec<usize>]) -> Vec<(usize, usize)> {
+    /* Pushes half-open [start, end) ranges through one map. Each row cuts the ranges that
+    haven't been mapped yet into the part it covers, which moves to the destination, and the
+    parts either side of it, which are left for the rows below. Whatever no row covers maps
+    to itself. */
+    let mut unmapped = ranges;
+    let mut mapped = Vec::new();
+
+    for row in rows {
+        if let [dest, source, range] = row.as_slice() {
+            let mut remaining = Vec::new();
+
+            for pending in unmapped {
+                let (overlap, leftovers) = cut(pending, (*source, source + range));
+                if let Some((overlap_start, overlap_end)) = overlap {
+                    mapped.push((
+                        dest + (overlap_start - source),
+                        dest + (overlap_end - source),
+                    ));
+                }
+                remaining.extend(leftovers);
+            }
+
+            unmapped = remaining;
+        }
+    }
+
+    mapped.extend(unmapped);
+    mapped
+}
+
+fn get_min_location_of_ranges(
+    seed_ranges: Vec<(usize, usize)>,
+    chain: &[&CategoryMap],
+) -> Option<usize> {
+    chain
+        .iter()
+        .fold(seed_ranges, |ranges, map| map_ranges(ranges, map.rows()))
+        .iter()
+        .map(|(start, _)| *start)
+        .min()
+}
+
+/// The seed line read as `start length` pairs, as half-open `[start, end)` ranges. A seed left
+/// without a length, a length of zero, or a range running past the largest number is an error
+/// rather than being quietly dropped.
+fn seed_ranges(seeds: &[usize]) -> Result<Vec<(usize, usize)>> {
+    seeds
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| match pair {
+            [start, 0] => Err(Error::Input(format!(
+                "seed range {} starting at {} is empty",
+                i + 1,
+                start
+            ))),
+            [start, len] => start
+                .checked_add(*len)
+                .map(|end| (*start, end))
+                .ok_or_else(|| {
+                    Error::Input(format!(
+                        "seed range {} starting at {} runs past the largest number",
+                        i + 1,
+                        start
+                    ))
+                }),
+            _ => Err(Error::Input(format!(
+                "the seed line has {} numbers, which don't pair up into `start length` ranges",
+                seeds.len()
+            ))),
+        })
+        .collect()
+}
+
+fn part_two(seeds: &[usize], chain: &[&CategoryMap]) -> Result<usize> {
+    get_min_location_of_ranges(seed_ranges(seeds)?, chain)
+        .ok_or_else(|| Error::Input("no seed ranges to plant".to_string()))
+}
+
+/// The maps from seed to location. Parsing only succeeds when there is such a chain.
+fn seed_chain(almanac: &Almanac) -> Vec<&CategoryMap> {
+    almanac
+        .chain(SEED, LOCATION)
+        .expect("parsing checks the almanac converts seeds into locations")
+}
+
+/// The whole seed-to-location chain folded into a single map.
+pub fn seed_to_location(almanac: &Almanac) -> PiecewiseMap {
+    almanac
+        .converter(SEED, LOCATION)
+        .expect("parsing checks the almanac converts seeds into locations")
+}
+
+/// Every range of seed numbers whose location falls in `locations`, a half-open range.
+pub fn seeds_for_locations(almanac: &Almanac, locations: Range) -> Vec<Range> {
+    seed_to_location(almanac).preimage(locations)
+}
+
+/// Every range of seed numbers that ends up at `location`.
+pub fn seeds_for_location(almanac: &Almanac, location: usize) -> Vec<Range> {
+    seeds_for_locations(almanac, (location, location + 1))
+}
+
+/// Part two by walking every single seed through the maps. Far too slow and memory hungry for
+/// the real input, but handy for cross-checking the range-based answer on small ones.
+pub fn part_two_per_seed(almanac: &Almanac) -> Result<usize> {
+    let mut all_seeds = Vec::new();
+    for (start, end) in seed_ranges(almanac.seeds())? {
+        all_seeds.extend(start..end);
+    }
+
+    Ok(get_min_location(&all_seeds, &seed_chain(almanac)))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Almanac;
+    type PartOne = usize;
+    type PartTwo = Result<usize>;
+
+    fn parse(map_contents: &str) -> Result<Self::Input> {
+        let almanac = parse_content(map_contents)?;
+
+        reject_issues("the almanac", &almanac.validate())?;
+
+        almanac.chain(SEED, LOCATION)?;
+
+        Ok(almanac)
+    }
+
+    fn part_one(almanac: &Self::Input) -> usize {
+        part_one(almanac.seeds(), &seed_chain(almanac))
+    }
+
+    fn part_two(almanac: &Self::Input) -> Result<usize> {
+        part_two(almanac.seeds(), &seed_chain(almanac))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    /// An almanac with a single seed-to-location map made of `rows`, which may overlap.
+    fn almanac(seeds: &str, rows: &str) -> Almanac {
+        Almanac::parse(&format!(
+            "seeds: {}\n\nseed-to-location map:\n{}",
+            seeds, rows
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), 35);
+        assert_eq!(Solution::part_two(&input).unwrap(), 46);
+    }
+
+    #[test]
+    fn ranges_agree_with_walking_every_seed() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Solution::part_two(&input).unwrap(),
+            part_two_per_seed(&input).unwrap()
+        );
+
+        for (seeds, rows) in [
+            // ranges cut by a row at either end and in the middle
+            ("5 10 40 3", "100 8 4\n0 12 3"),
+            // a range ending exactly where a row starts, and a row covering a whole range
+            ("0 10 20 5", "50 10 5\n1 18 10"),
+            // rows that only overlap each other's leftovers
+            ("30 20", "0 35 5\n3 30 10"),
+        ] {
+            let input = almanac(seeds, rows);
+            assert_eq!(
+                Solution::part_two(&input).unwrap(),
+                part_two_per_seed(&input).unwrap(),
+                "seeds {} with rows {:?}",
+                seeds,
+                rows
+            );
+        }
+    }
+
+    #[test]
+    fn composed_chain_matches_walking_each_map() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        let composed = seed_to_location(&input);
+
+        for seed in 0..120 {
+            assert_eq!(
+                composed.get(seed),
+                get_min_location(&[seed], &seed_chain(&input)),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn locations_map_back_to_their_seeds() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+
+        // the puzzle text walks seed 82 to location 46, and seed 79 to location 82
+        assert!(seeds_for_location(&input, 46)
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&82)));
+        assert!(seeds_for_location(&input, 82)
+            .iter()
+            .any(|(start, end)| (*start..*end).contains(&79)));
+
+        let composed = seed_to_location(&input);
+        for (start, end) in seeds_for_locations(&input, (40, 60)) {
+            for seed in start..end {
+                assert!((40..60).contains(&composed.get(seed)), "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn scanning_locations_upward_finds_the_lowest() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        let planted = seed_ranges(input.seeds()).unwrap();
+
+        let lowest = (0..)
+            .find(|location| {
+                seeds_for_location(&input, *location).iter().any(|seeds| {
+                    planted
+                        .iter()
+                        .any(|planted| cut(*seeds, *planted).0.is_some())
+                })
+            })
+            .unwrap();
+
+        assert_eq!(lowest, Solution::part_two(&input).unwrap());
+    }
+
+    #[test]
+    fn unmapped_ranges_keep_their_numbers() {
+        assert_eq!(map_ranges(vec![(3, 7)], &[vec![0, 10, 5]]), vec![(3, 7)]);
+        assert_eq!(
+            map_ranges(vec![(3, 7)], &[vec![100, 5, 10]]),
+            vec![(100, 102), (3, 5)]
+        );
+    }
+
+    #[test]
+    fn chain_follows_the_headers() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        let chain = input.chain("seed", "location").unwrap();
+
+        assert_eq!(chain.len(), 7);
+        assert_eq!(chain[3].source(), "water");
+        assert_eq!(input.chain("soil", "humidity").unwrap().len(), 5);
+        assert!(input.chain("soil", "soil").unwrap().is_empty());
+        assert!(input.chain("location", "seed").is_err());
+    }
+
+    #[test]
+    fn converts_between_any_two_categories() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+
+        // from the puzzle text: seed 79 has soil 81, fertilizer 81, ..., humidity 78
+        assert_eq!(input.convert("seed", "soil", 79).unwrap(), 81);
+        assert_eq!(input.convert("soil", "humidity", 81).unwrap(), 78);
+        assert_eq!(input.convert("temperature", "location", 78).unwrap(), 82);
+    }
+
+    #[test]
+    fn maps_may_be_reordered_renamed_or_extra() {
+        let shuffled = "seeds: 79 14 55 13
+
+soil-to-dirt map:
+1 2 3
+
+humidity-to-location map:
+60 56 37
+56 93 4
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-humidity map:
+0 0 1
+";
+        let input = Solution::parse(shuffled).unwrap();
+        // seed 79 -> soil 81 -> humidity 81 -> location 85
+        assert_eq!(input.convert("seed", "location", 79).unwrap(), 85);
+        assert_eq!(Solution::part_one(&input), 13);
+    }
+
+    #[test]
+    fn almanac_without_a_location_chain_is_rejected() {
+        assert!(Solution::parse("seeds: 1 2\n\nseed-to-soil map:\n1 2 3").is_err());
+        assert!(Solution::parse("seeds: 1 2\n\nseed to soil map:\n1 2 3").is_err());
+    }
+
+    #[test]
+    fn validation_reports_bad_rows_by_line() {
+        let input = almanac(
+            "1 2",
+            "10 0 5\n20 3 5\n1 2\n5 5 0\n7 18446744073709551615 1\n30 100 1",
+        );
+        let kinds: Vec<(usize, IssueKind)> = input
+            .validate()
+            .into_iter()
+            .map(|issue| (issue.line, issue.kind))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                (5, IssueKind::Overlap(4)),
+                (6, IssueKind::MalformedRow(2)),
+                (7, IssueKind::ZeroLength),
+                (8, IssueKind::Overflow),
+            ]
+        );
+        assert!(Solution::parse(EXAMPLE).unwrap().validate().is_empty());
+    }
+
+    #[test]
+    fn repeated_maps_are_reported() {
+        let almanac = "seeds: 1 2\n\nseed-to-soil map:\n0 0 5\n\nsoil-to-location map:\n0 0 5\n\n\
+                       seed-to-soil map:\n10 0 5";
+        let input = Almanac::parse(almanac).unwrap();
+        assert_eq!(
+            input.validate(),
+            vec![Issue {
+                line: 9,
+                map: "seed-to-soil".to_string(),
+                kind: IssueKind::DuplicateMap(3),
+            }]
+        );
+        assert!(matches!(
+            Solution::parse(almanac),
+            Err(Error::Input(message)) if message.contains("line 9 (seed-to-soil map): the map repeats the one headed on line 3")
+        ));
+    }
+
+    #[test]
+    fn overlap_inside_a_longer_range_is_reported() {
+        // the third row sits inside the first even though the second row ends before it
+        let input = almanac("1 2", "0 10 100\n0 20 5\n0 50 5");
+        let overlapping: Vec<usize> = input.validate().iter().map(|issue| issue.line).collect();
+
+        assert_eq!(overlapping, vec![5, 6]);
+    }
+
+    #[test]
+    fn invalid_almanac_fails_to_parse() {
+        let err = Solution::parse("seeds: 1 2\n\nseed-to-location map:\n1 2")
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().contains("line 4 (seed-to-location map)"));
+    }
+
+    #[test]
+    fn seeds_that_do_not_pair_into_ranges_are_an_error() {
+        let unpaired = almanac("5", "1 2 3");
+        assert_eq!(Solution::part_one(&unpaired), 5);
+        assert!(matches!(
+            Solution::part_two(&unpaired),
+            Err(Error::Input(_))
+        ));
+        assert!(part_two_per_seed(&unpaired).is_err());
+
+        let empty = almanac("5 0 10 3", "1 2 3");
+        assert!(matches!(
+            Solution::part_two(&empty),
+            Err(Error::Input(message)) if message.contains("seed range 1 starting at 5 is empty")
+        ));
+    }
+
+    #[test]
+    fn seed_ranges_past_the_largest_number_are_an_error() {
+        let input = almanac("1 2 18446744073709551615 2", "1 2 3");
+        assert!(matches!(
+            Solution::part_two(&input),
+            Err(Error::Input(message)) if message.contains("seed range 2 starting at 18446744073709551615 runs past")
+        ));
+    }
+
+    #[test]
+    fn missing_seeds_are_rejected() {
+        assert!(Solution::parse("seed-to-soil map:\n50 98 2").is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+/// Numbers in `[start, end)` map to themselves plus `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: usize,
+    pub end: usize,
+    pub offset: isize,
+}
+
+impl Segment {
+    /// Where the segment's numbers end up, as a half-open range.
+    pub fn destination(&self) -> (usize, usize) {
+        (shift(self.start, self.offset), shift(self.end, self.offset))
+    }
+}
+
+/// A half-open `[start, end)` range of numbers.
+pub type Range = (usize, usize);
+
+fn shift(value: usize, offset: isize) -> usize {
+    value
+        .checked_add_signed(offset)
+        .expect("almanac numbers should fit in a usize")
+}
+
+/// Splits `[start, end)` around `[source, source_end)` into the part they share, if any, and
+/// the parts either side of it.
+pub(crate) fn cut((start, end): Range, (source, source_end): Range) -> (Option<Range>, Vec<Range>) {
+    let mut leftovers = Vec::new();
+
+    if start < source {
+        leftovers.push((start, end.min(source)));
+    }
+    if end > source_end {
+        leftovers.push((start.max(source_end), end));
+    }
+
+    let (overlap_start, overlap_end) = (start.max(source), end.min(source_end));
+    let overlap = (overlap_start < overlap_end).then_some((overlap_start, overlap_end));
+
+    (overlap, leftovers)
+}
+
+/// A map from numbers to numbers made of sorted, non-overlapping [`Segment`]s that together
+/// cover every number from 0 up to `usize::MAX`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    /// The map that leaves every number where it is.
+    pub fn identity() -> Self {
+        PiecewiseMap {
+            segments: vec![Segment {
+                start: 0,
+                end: usize::MAX,
+                offset: 0,
+            }],
+        }
+    }
+
+    /// Builds the map described by an almanac map's `[dest, source, range]` rows. Where rows
+    /// overlap the first one wins, like a lookup that stops at the first matching row, and
+    /// numbers no row covers map to themselves.
+    pub fn from_rows(rows: &[Vec<usize>]) -> Self {
+        let mut unmapped = vec![(0, usize::MAX)];
+        let mut segments = Vec::new();
+
+        for row in rows {
+            if let [dest, source, range] = row.as_slice() {
+                let offset = *dest as isize - *source as isize;
+                let mut remaining = Vec::new();
+
+                for pending in unmapped {
+                    let (overlap, leftovers) = cut(pending, (*source, source + range));
+                    if let Some((start, end)) = overlap {
+                        segments.push(Segment { start, end, offset });
+                    }
+                    remaining.extend(leftovers);
+                }
+
+                unmapped = remaining;
+            }
+        }
+
+        segments.extend(unmapped.into_iter().map(|(start, end)| Segment {
+            start,
+            end,
+            offset: 0,
+        }));
+        segments.sort_by_key(|segment| segment.start);
+
+        PiecewiseMap { segments }.merged()
+    }
+
+    /// The map that applies `self` and then `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> Self {
+        let mut segments = Vec::new();
+
+        for segment in &self.segments {
+            let (image_start, image_end) = segment.destination();
+            let first = next
+                .segments
+                .partition_point(|candidate| candidate.end <= image_start);
+
+            for candidate in next.segments[first..]
+                .iter()
+                .take_while(|candidate| candidate.start < image_end)
+            {
+                let start = image_start.max(candidate.start);
+                let end = image_end.min(candidate.end);
+
+                segments.push(Segment {
+                    start: shift(start, -segment.offset),
+                    end: shift(end, -segment.offset),
+                    offset: segment.offset + candidate.offset,
+                });
+            }
+        }
+
+        PiecewiseMap { segments }.merged()
+    }
+
+    /// Joins neighbouring segments that move their numbers by the same offset.
+    fn merged(self) -> Self {
+        let mut segments: Vec<Segment> = Vec::with_capacity(self.segments.len());
+
+        for segment in self.segments {
+            match segments.last_mut() {
+                Some(last) if last.end == segment.start && last.offset == segment.offset => {
+                    last.end = segment.end;
+                }
+                _ => segments.push(segment),
+            }
+        }
+
+        PiecewiseMap { segments }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Every range of numbers that this map sends into `target`, sorted and with touching
+    /// ranges joined.
+    pub fn preimage(&self, (target_start, target_end): Range) -> Vec<Range> {
+        let mut ranges: Vec<Range> = self
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                let (destination_start, destination_end) = segment.destination();
+                let start = target_start.max(destination_start);
+                let end = target_end.min(destination_end);
+
+                (start < end).then(|| (shift(start, -segment.offset), shift(end, -segment.offset)))
+            })
+            .collect();
+        ranges.sort();
+
+        // segments never overlap, so their pieces of the preimage can only touch
+        let mut joined: Vec<Range> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match joined.last_mut() {
+                Some(last) if last.1 == start => last.1 = end,
+                _ => joined.push((start, end)),
+            }
+        }
+
+        joined
+    }
+
+    /// Looks `value` up with a binary search over the segments.
+    pub fn get(&self, value: usize) -> usize {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.end <= value);
+
+        match self.segments.get(index) {
+            Some(segment) if segment.start <= value => shift(value, segment.offset),
+            _ => value,
+        }
+    }
+
+    /// One row per segment, with a header.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            "source_start,source_end,destination_start,destination_end,offset\n".to_string();
+
+        for segment in &self.segments {
+            let (destination_start, destination_end) = segment.destination();
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                segment.start, segment.end, destination_start, destination_end, segment.offset
+            ));
+        }
+
+        csv
+    }
+}
+
+/// Prints one segment per line, e.g. `[50, 98) -> [52, 100) (+2)`.
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            let (destination_start, destination_end) = segment.destination();
+            writeln!(
+                f,
+                "[{}, {}) -> [{}, {}) ({:+})",
+                segment.start, segment.end, destination_start, destination_end, segment.offset
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_row_wins_where_rows_overlap() {
+        let map = PiecewiseMap::from_rows(&[vec![100, 10, 10], vec![200, 15, 10]]);
+
+        assert_eq!(map.get(9), 9);
+        assert_eq!(map.get(15), 105);
+        assert_eq!(map.get(22), 207);
+        assert_eq!(map.get(25), 25);
+        assert_eq!(map.segments().len(), 4);
+    }
+
+    #[test]
+    fn composing_merges_segments_that_cancel_out() {
+        let forward = PiecewiseMap::from_rows(&[vec![60, 50, 10]]);
+        let back = PiecewiseMap::from_rows(&[vec![50, 60, 10], vec![60, 50, 10]]);
+
+        assert_eq!(forward.then(&back).get(55), 55);
+        assert_eq!(forward.then(&back).get(65), 55);
+        // [0, 50) and [50, 60) both end up unmoved, so they join into one segment
+        assert_eq!(forward.then(&back).segments().len(), 3);
+        assert_eq!(forward.then(&PiecewiseMap::identity()), forward);
+    }
+
+    #[test]
+    fn prints_and_exports_segments() {
+        let map = PiecewiseMap::from_rows(&[vec![52, 50, 48]]);
+
+        assert!(map
+            .to_string()
+            .starts_with("[0, 50) -> [0, 50) (+0)\n[50, 98) -> [52, 100) (+2)\n"));
+        assert_eq!(
+            map.to_csv().lines().take(3).collect::<Vec<_>>(),
+            vec![
+                "source_start,source_end,destination_start,destination_end,offset",
+                "0,50,0,50,0",
+                "50,98,52,100,2",
+            ]
+        );
+    }
+}
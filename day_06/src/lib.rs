@@ -0,0 +1,309 @@
+use aoc_common::parse::{lines, parse_numbers, Line};
+use aoc_common::{Error, ParseErrorKind, Result, Solver};
+
+mod window;
+
+pub use window::{table, Window};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/races.txt");
+
+/// One race: how long it lasts and the furthest anyone has travelled in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record: u64,
+}
+
+impl Race {
+    /// The number of hold times that beat the record; see [`winning_holds`].
+    pub fn winning_holds(&self) -> u64 {
+        winning_holds(self.time, self.record)
+    }
+
+    pub fn window(&self) -> Window {
+        Window::of(*self)
+    }
+}
+
+/// The race sheet, read both as separate races (part one) and as a single race with the
+/// spaces between the digits ignored (part two). The single race is only read when asked for,
+/// so a kerned number too big for a `u64` fails part two alone.
+pub struct Races {
+    races: Vec<Race>,
+    sheet: String,
+}
+
+impl Races {
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    pub fn kerned(&self) -> Result<Race> {
+        parse_kerned_race(&self.sheet)
+    }
+}
+
+/// A line of the race sheet along with the text after its label.
+type Labelled<'a> = (Line<'a>, &'a str);
+
+/// Finds the `Time:` and `Distance:` lines, in whichever order they come. Blank lines and the
+/// spacing around labels are ignored.
+fn labelled_lines(sheet: &str) -> Result<(Labelled<'_>, Labelled<'_>)> {
+    let mut time = None;
+    let mut distance = None;
+
+    for line in lines(sheet).filter(|line| !line.text.trim().is_empty()) {
+        let (label, numbers) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.error_at_end(ParseErrorKind::Expected("`:`")))?;
+        let label = label.trim();
+
+        let slot = match label {
+            "Time" => &mut time,
+            "Distance" => &mut distance,
+            _ => {
+                return Err(line.error(
+                    label,
+                    ParseErrorKind::Expected("a `Time` or `Distance` label"),
+                ))
+            }
+        };
+        if slot.is_some() {
+            return Err(line.error(
+                label,
+                ParseErrorKind::Expected("each label on only one line"),
+            ));
+        }
+        *slot = Some((line, numbers));
+    }
+
+    match (time, distance) {
+        (Some(time), Some(distance)) => Ok((time, distance)),
+        _ => Err(Error::Input(
+            "expected a `Time:` line and a `Distance:` line".to_string(),
+        )),
+    }
+}
+
+/// Reads each column of the sheet as its own race.
+pub fn parse_races(sheet: &str) -> Result<Vec<Race>> {
+    let ((time_line, times), (distance_line, distances)) = labelled_lines(sheet)?;
+    let times: Vec<u64> = parse_numbers(&time_line, times)?;
+    let records: Vec<u64> = parse_numbers(&distance_line, distances)?;
+
+    if times.len() != records.len() {
+        return Err(Error::Input(format!(
+            "line {} lists {} race times but line {} lists {} record distances",
+            time_line.number,
+            times.len(),
+            distance_line.number,
+            records.len()
+        )));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(records)
+        .map(|(time, record)| Race { time, record })
+        .collect())
+}
+
+/// Reads the whole sheet as one race, ignoring the spaces between the digits.
+pub fn parse_kerned_race(sheet: &str) -> Result<Race> {
+    let (time, distance) = labelled_lines(sheet)?;
+
+    Ok(Race {
+        time: kerned(time)?,
+        record: kerned(distance)?,
+    })
+}
+
+fn kerned((line, numbers): Labelled) -> Result<u64> {
+    let combined_string: String = numbers.chars().filter(|c| !c.is_whitespace()).collect();
+
+    combined_string
+        .parse::<u64>()
+        .map_err(|_| line.error(numbers.trim(), ParseErrorKind::InvalidNumber))
+}
+
+/// The number of ways to beat `record` in a race lasting `time`: holds `t` where
+/// `t * (time - t) > record`.
+///
+/// If we plotted distance against time waiting we'd get a parabola symmetrical around
+/// `time / 2`, crossing the record at `(time ± sqrt(time² - 4 * record)) / 2`. The square root
+/// is taken exactly on integers, so this is right for any `u64` race, where a float would
+/// round the roots to the wrong hold.
+pub fn winning_holds(time: u64, record: u64) -> u64 {
+    // by symmetry the last winning hold is `time - first`
+    first_winning_hold(time, record).map_or(0, |first| time - 2 * first + 1)
+}
+
+/// The shortest hold that beats `record`, or `None` if no hold does.
+pub fn first_winning_hold(time: u64, record: u64) -> Option<u64> {
+    let (time, record) = (time as u128, record as u128);
+    let wins = |hold: u128| hold * (time - hold) > record;
+
+    // the best distance is time² / 4, so no hold can beat a record at least that long
+    let discriminant = (time * time).checked_sub(4 * record)?;
+
+    // isqrt rounds down, so this is at or just below the first winning hold
+    let mut first = (time - discriminant.isqrt()) / 2;
+    while first <= time / 2 && !wins(first) {
+        first += 1;
+    }
+
+    (first <= time / 2).then_some(first as u64)
+}
+
+/// [`winning_holds`] by trying every hold, to check it against.
+pub fn winning_holds_brute_force(time: u64, record: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| (*hold as u128) * ((time - hold) as u128) > record as u128)
+        .count() as u64
+}
+
+/// The product of the ways to win each race, as wide as an answer can be: a handful of long
+/// races soon outgrows a `u64`.
+fn part_one(races: &[Race]) -> Result<i128> {
+    races
+        .iter()
+        .try_fold(1i128, |product, race| {
+            product.checked_mul(race.winning_holds().into())
+        })
+        .ok_or(Error::Overflow("the product of the ways to win each race"))
+}
+
+fn part_two(race: Race) -> u64 {
+    race.winning_holds()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Races;
+    type PartOne = Result<i128>;
+    type PartTwo = Result<u64>;
+
+    fn parse(races_text: &str) -> Result<Self::Input> {
+        Ok(Races {
+            races: parse_races(races_text)?,
+            sheet: races_text.to_string(),
+        })
+    }
+
+    fn part_one(races: &Self::Input) -> Result<i128> {
+        part_one(&races.races)
+    }
+
+    fn part_two(races: &Self::Input) -> Result<u64> {
+        Ok(part_two(races.kerned()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 288);
+        assert_eq!(Solution::part_two(&input).unwrap(), 71503);
+    }
+
+    #[test]
+    fn record_equal_to_best_distance_has_no_wins() {
+        // holding for 3 of 6ms travels 9mm, which only ties the record
+        let input = Solution::parse("Time: 6\nDistance: 9").unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        for time in 0..60 {
+            for record in 0..=time * time / 4 + 2 {
+                assert_eq!(
+                    winning_holds(time, record),
+                    winning_holds_brute_force(time, record),
+                    "time {} record {}",
+                    time,
+                    record
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn handles_races_near_the_top_of_u64() {
+        // holding for 2^31 of 2^32ms travels 2^62mm, and one ms either side falls 1mm short
+        let time = 1 << 32;
+        assert_eq!(winning_holds(time, (1 << 62) - 2), 3);
+        assert_eq!(winning_holds(time, (1 << 62) - 1), 1);
+        assert_eq!(winning_holds(time, 1 << 62), 0);
+
+        assert_eq!(winning_holds(u64::MAX, 0), u64::MAX - 1);
+        // a 1ms hold travels u64::MAX - 1, just short of the record
+        assert_eq!(winning_holds(u64::MAX, u64::MAX), u64::MAX - 3);
+    }
+
+    #[test]
+    fn single_line_is_rejected() {
+        assert!(Solution::parse("Time: 7 15 30").is_err());
+    }
+
+    #[test]
+    fn lines_are_matched_by_label() {
+        let sheet = "\nDistance :9   40 200\n  Time:7 15    30\n";
+        assert_eq!(
+            parse_races(sheet).unwrap(),
+            vec![
+                Race { time: 7, record: 9 },
+                Race {
+                    time: 15,
+                    record: 40
+                },
+                Race {
+                    time: 30,
+                    record: 200
+                },
+            ]
+        );
+        assert_eq!(
+            parse_kerned_race(sheet).unwrap(),
+            Race {
+                time: 71530,
+                record: 940200
+            }
+        );
+    }
+
+    #[test]
+    fn kerned_overflow_only_fails_part_two() {
+        // read as one race the time is 22 digits long, but each column fits
+        let input = Solution::parse("Time: 99999999999 99999999999\nDistance: 1 1").unwrap();
+        assert_eq!(
+            Solution::part_one(&input).unwrap(),
+            99999999998 * 99999999998
+        );
+        assert!(matches!(
+            Solution::part_two(&input),
+            Err(Error::Parse(err)) if err.kind == ParseErrorKind::InvalidNumber
+        ));
+    }
+
+    #[test]
+    fn mismatched_columns_and_unknown_labels_are_rejected() {
+        assert!(matches!(
+            parse_races("Time: 7 15 30\nDistance: 9 40"),
+            Err(Error::Input(message)) if message.contains("3 race times")
+        ));
+        assert!(matches!(
+            parse_races("Time: 7\nSpeed: 9"),
+            Err(Error::Parse(err)) if (err.line, err.text.as_str()) == (2, "Speed")
+        ));
+        assert!(parse_races("Time: 7\nTime: 7\nDistance: 9").is_err());
+    }
+}
@@ -1,94 +1,96 @@
-use std::fs;
+use aoc_common::{Answer, IntoAnswer, Result, Solver, Source};
+use day_06::{Races, Solution, Window};
+use serde::Serialize;
+use std::process::ExitCode;
 
-fn parse_race_part_one(races: &String) -> (Vec<usize>, Vec<usize>) {
-    if let [time, distance] = races
-        .lines()
-        .map(|line| {
-            if line.starts_with("Time:") {
-                line[line.find(":").unwrap() + 1..]
-                    .trim()
-                    .split_whitespace()
-                    .map(|char| char.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>()
-            } else {
-                line[line.find(":").unwrap() + 1..]
-                    .trim()
-                    .split_whitespace()
-                    .map(|char| char.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>()
-            }
-        })
-        .collect::<Vec<Vec<usize>>>()
-        .as_slice()
-    {
-        (time.to_vec(), distance.to_vec())
-    } else {
-        (Vec::new(), Vec::new())
-    }
-}
+/// Flag, anywhere on the command line, asking for a single JSON document instead of text.
+const JSON_FLAG: &str = "--json";
 
-fn parse_race_part_two(race: &String) -> (usize, usize) {
-    if let [time, distance] = race
-        .lines()
-        .map(|line| {
-            let combined_string: String = line[line.find(":").unwrap() + 1..]
-                .chars()
-                .filter(|c| !c.is_whitespace())
-                .collect();
+/// One part's outcome in the JSON report: `{"answer": ...}` or `{"error": ...}`. Answers go out
+/// as text, since they can be wider than a JSON number reliably holds.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Answer(String),
+    Error(String),
+}
 
-            combined_string.parse::<usize>().unwrap()
-        })
-        .collect::<Vec<usize>>()
-        .as_slice()
-    {
-        (*time, *distance)
-    } else {
-        (0, 0)
+impl From<Result<Answer>> for Outcome {
+    fn from(answer: Result<Answer>) -> Self {
+        match answer {
+            Ok(answer) => Outcome::Answer(answer.to_string()),
+            Err(err) => Outcome::Error(err.to_string()),
+        }
     }
 }
 
-fn part_one(time: Vec<usize>, distance: Vec<usize>) {
-    let value = time
-        .into_iter()
-        .zip(distance)
-        .map(|(max_time, record_distance)| {
-            let mut unique_wins = 0;
-            for time_waiting in 1..(max_time) {
-                let time_moving = max_time - time_waiting;
+#[derive(Serialize)]
+struct Report {
+    part_one: Outcome,
+    part_two: Outcome,
+    races: Vec<Window>,
+    kerned: Option<Window>,
+}
 
-                if time_moving * time_waiting > record_distance {
-                    unique_wins += 1;
-                }
-            }
-            unique_wins
-        })
-        .fold(1, |acc, val| acc * val);
+/// Prints the answers and then each race's winning window as a markdown table. With `--json`,
+/// prints only one JSON object holding both the answers and the windows.
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|arg| arg == JSON_FLAG);
+    args.retain(|arg| arg != JSON_FLAG);
 
-    println!("Part one: {}", value);
-}
+    let source = Source::resolve(args.into_iter().next().map(Source::from), day_06::INPUT);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
 
-fn part_two(time: usize, record_distance: usize) {
-    // the idea here is that if we plotted a graph where x-axis is time waiting, and y-axis
-    // is the total distance that can be travelled, we'd get a symmetrical parabolic curve
-    // of the form y = -ax. Since it's symmetrical, we simply need to find the time x where
-    // it is the largest distance that is as yet LESS than record_distance. and we know the
-    // the total number of ways, is simply the total_time minus (2 * x).
-    let mut time_waiting: usize = 0;
-    while (time - time_waiting) * (time_waiting) < record_distance {
-        time_waiting += 1;
+    if !json {
+        let exit_code = aoc_common::print_answers::<Solution>(&input, source.path());
+        if let Ok(races) = Solution::parse(&input) {
+            let (windows, kerned) = windows(&races);
+            println!("\nSeparate races:\n\n{}", day_06::table(&windows));
+            if let Some(kerned) = kerned {
+                println!("Kerned race:\n\n{}", day_06::table(&[kerned]));
+            }
+        }
+        return exit_code;
     }
 
-    // (time + 1) because because the 0th time is itself a coordinate we need to account for.
-    println!("Part two: {}", (time + 1) - (2 * time_waiting));
-}
+    let races = match Solution::parse(&input) {
+        Ok(races) => races,
+        Err(err) => {
+            eprintln!("error: {}", err.in_file(source.path()));
+            return ExitCode::FAILURE;
+        }
+    };
 
-fn main() {
-    let races_text =
-        fs::read_to_string("races.txt").expect("Should have been able to open the races file");
+    let (windows, kerned) = windows(&races);
+    let report = Report {
+        part_one: Solution::part_one(&races).into_answer().into(),
+        part_two: Solution::part_two(&races).into_answer().into(),
+        races: windows,
+        kerned,
+    };
+    // written straight out rather than through a `serde_json::Value`, which can't hold the
+    // 128-bit distances
+    println!(
+        "{}",
+        serde_json::to_string(&report).expect("the report is plain data")
+    );
 
-    let (time, distance) = parse_race_part_one(&races_text);
-    part_one(time, distance);
+    match (&report.part_one, &report.part_two) {
+        (Outcome::Answer(_), Outcome::Answer(_)) => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
 
-    let (time, distance) = parse_race_part_two(&races_text);
-    part_two(time, distance);
+/// Every race's window, and the kerned race's unless it is too long to read; that has already
+/// been reported as part two's error.
+fn windows(races: &Races) -> (Vec<Window>, Option<Window>) {
+    let windows = races.races().iter().map(|race| race.window()).collect();
+    (windows, races.kerned().ok().map(|race| race.window()))
 }
@@ -0,0 +1,111 @@
+use crate::{first_winning_hold, Race};
+use serde::Serialize;
+
+/// Where a race's winning holds lie on the parabola of distance against hold time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Window {
+    pub time: u64,
+    pub record: u64,
+    /// How many holds beat the record.
+    pub ways: u64,
+    /// The shortest and longest holds that beat the record, or `None` if no hold does.
+    pub first_hold: Option<u64>,
+    pub last_hold: Option<u64>,
+    /// The hold that goes furthest. When the race time is odd, holding 1ms longer ties it.
+    pub best_hold: u64,
+    pub best_distance: u128,
+    /// How far past the record the best hold goes; negative when it falls short.
+    pub margin: i128,
+}
+
+impl Window {
+    pub fn of(race: Race) -> Self {
+        let first_hold = first_winning_hold(race.time, race.record);
+        let best_hold = race.time / 2;
+        let best_distance = best_hold as u128 * (race.time - best_hold) as u128;
+
+        Window {
+            time: race.time,
+            record: race.record,
+            ways: race.winning_holds(),
+            first_hold,
+            last_hold: first_hold.map(|first| race.time - first),
+            best_hold,
+            best_distance,
+            margin: best_distance as i128 - race.record as i128,
+        }
+    }
+}
+
+fn hold(hold: Option<u64>) -> String {
+    hold.map_or("-".to_string(), |hold| hold.to_string())
+}
+
+/// Renders the windows as a markdown table, numbering the races from 1.
+pub fn table(windows: &[Window]) -> String {
+    let mut table = "| Race | Time | Record | Ways | First hold | Last hold | Best hold | Best distance | Margin |\n\
+         |-----:|-----:|-------:|-----:|-----------:|----------:|----------:|--------------:|-------:|\n"
+        .to_string();
+
+    for (race, window) in windows.iter().enumerate() {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            race + 1,
+            window.time,
+            window.record,
+            window.ways,
+            hold(window.first_hold),
+            hold(window.last_hold),
+            window.best_hold,
+            window.best_distance,
+            window.margin
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_matches_the_parabola() {
+        let window = Race { time: 7, record: 9 }.window();
+        assert_eq!(
+            (window.first_hold, window.last_hold, window.ways),
+            (Some(2), Some(5), 4)
+        );
+        assert_eq!(
+            (window.best_hold, window.best_distance, window.margin),
+            (3, 12, 3)
+        );
+
+        // the best hold only ties the record
+        let tied = Race { time: 6, record: 9 }.window();
+        assert_eq!((tied.first_hold, tied.ways, tied.margin), (None, 0, 0));
+    }
+
+    #[test]
+    fn renders_a_table_and_json() {
+        let windows = [
+            Race { time: 7, record: 9 }.window(),
+            Race { time: 6, record: 9 }.window(),
+        ];
+
+        let table = table(&windows);
+        assert_eq!(
+            table.lines().nth(2),
+            Some("| 1 | 7 | 9 | 4 | 2 | 5 | 3 | 12 | 3 |")
+        );
+        assert_eq!(
+            table.lines().nth(3),
+            Some("| 2 | 6 | 9 | 0 | - | - | 3 | 9 | 0 |")
+        );
+
+        assert_eq!(
+            serde_json::to_string(&windows[1]).unwrap(),
+            r#"{"time":6,"record":9,"ways":0,"first_hold":null,"last_hold":null,"best_hold":3,"best_distance":9,"margin":0}"#
+        );
+    }
+}
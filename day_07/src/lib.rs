@@ -0,0 +1,455 @@
+use aoc_common::parse::{lines, parse_number, Line};
+use aoc_common::{Error, ParseErrorKind, Result, Solver};
+use std::{cmp::Reverse, collections::HashMap};
+
+mod report;
+mod verify;
+
+pub use report::{explain, to_csv, RankedHand};
+pub use verify::{verify_wildcards, Mismatch};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/hands.txt");
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum Hand {
+    HighCard([Card; 5]),
+    OnePair([Card; 5]),
+    TwoPair([Card; 5]),
+    ThreeOfAKind([Card; 5]),
+    FullHouse([Card; 5]),
+    FourOfAKind([Card; 5]),
+    FiveOfAKind([Card; 5]),
+}
+
+impl Hand {
+    /// How strong the hand's type is, from 0 for a high card to 6 for five of a kind,
+    /// ignoring its cards.
+    fn strength(&self) -> usize {
+        match self {
+            Hand::HighCard(_) => 0,
+            Hand::OnePair(_) => 1,
+            Hand::TwoPair(_) => 2,
+            Hand::ThreeOfAKind(_) => 3,
+            Hand::FullHouse(_) => 4,
+            Hand::FourOfAKind(_) => 5,
+            Hand::FiveOfAKind(_) => 6,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Hand::HighCard(_) => "high card",
+            Hand::OnePair(_) => "one pair",
+            Hand::TwoPair(_) => "two pair",
+            Hand::ThreeOfAKind(_) => "three of a kind",
+            Hand::FullHouse(_) => "full house",
+            Hand::FourOfAKind(_) => "four of a kind",
+            Hand::FiveOfAKind(_) => "five of a kind",
+        }
+    }
+}
+
+/// Where wildcards rank when breaking ties between hands of the same type.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum WildRank {
+    /// Below every other card, as a [`Card::Joker`].
+    Lowest,
+    /// Wherever the card they were dealt as ranks.
+    Natural,
+}
+
+/// How two hands of the same type are ordered.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TieBreak {
+    /// Compare the first card of each hand, then the second and so on, as in Camel Cards.
+    CardOrder,
+    /// Compare the ranks of the largest groups first, as in poker, so `22AAA` beats `KKK22`.
+    GroupedRanks,
+}
+
+/// A variant of Camel Cards: which card, if any, is wild, and how hands are ordered.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Rules {
+    /// The card that joins whichever group makes the strongest hand.
+    pub wild: Option<Card>,
+    pub wild_rank: WildRank,
+    pub tie_break: TieBreak,
+}
+
+impl Rules {
+    /// Part one: `J` is a plain jack.
+    pub const JACKS: Rules = Rules {
+        wild: None,
+        wild_rank: WildRank::Natural,
+        tie_break: TieBreak::CardOrder,
+    };
+
+    /// Part two: `J` is a joker, which is wild but the weakest card on its own.
+    pub const JOKERS: Rules = Rules {
+        wild: Some(Card::Jack),
+        wild_rank: WildRank::Lowest,
+        tie_break: TieBreak::CardOrder,
+    };
+
+    fn is_wild(&self, card: Card) -> bool {
+        self.wild == Some(card)
+    }
+}
+
+impl Card {
+    /// Every card that can be dealt, weakest first.
+    pub const DECK: [Card; 13] = [
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Jack,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ];
+
+    pub fn from_char(c: char) -> Option<Card> {
+        match c {
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => None,
+        }
+    }
+
+    /// The letter the card is written as; a joker is written `J`.
+    pub fn to_char(self) -> char {
+        match self {
+            Card::Joker | Card::Jack => 'J',
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        }
+    }
+}
+
+fn string_to_card_array(line: &Line, s: &str) -> Result<[Card; 5]> {
+    let cards = s
+        .char_indices()
+        .map(|(i, c)| {
+            Card::from_char(c).ok_or_else(|| {
+                line.error(
+                    &s[i..i + c.len_utf8()],
+                    ParseErrorKind::Expected("a card (2-9, T, J, Q, K or A)"),
+                )
+            })
+        })
+        .collect::<Result<Vec<Card>>>()?;
+
+    cards
+        .try_into()
+        .map_err(|_| line.error(s, ParseErrorKind::Expected("a hand of exactly 5 cards")))
+}
+
+/// How many of each card that isn't wild the hand holds.
+fn card_counts(cards: &[Card; 5], rules: &Rules) -> HashMap<Card, usize> {
+    cards
+        .iter()
+        .filter(|card| !rules.is_wild(**card))
+        .fold(HashMap::new(), |mut mapper, card| {
+            *mapper.entry(*card).or_insert(0) += 1;
+            mapper
+        })
+}
+
+/// The card with the highest count, the strongest one when several share it.
+fn most_common(card_counts: &HashMap<Card, usize>) -> Option<Card> {
+    card_counts
+        .iter()
+        .max_by_key(|(card, count)| (**count, **card))
+        .map(|(card, _)| *card)
+}
+
+/// The card `parse_hand` counts the wildcards in `cards` as, or `None` if the hand has no
+/// wildcards or nothing but wildcards.
+pub fn joker_target(cards: &[Card; 5], rules: &Rules) -> Option<Card> {
+    if cards.iter().any(|card| rules.is_wild(*card)) {
+        most_common(&card_counts(cards, rules))
+    } else {
+        None
+    }
+}
+
+fn parse_hand(cards: &[Card; 5], rules: &Rules) -> Hand {
+    let card_array = cards.map(|card| {
+        if rules.is_wild(card) && rules.wild_rank == WildRank::Lowest {
+            Card::Joker
+        } else {
+            card
+        }
+    });
+
+    let mut card_counts = card_counts(cards, rules);
+    let wild_count = cards.iter().filter(|card| rules.is_wild(**card)).count();
+
+    // give all the wildcards to the highest occuring card. if every card is wild, they make
+    // five of a kind on their own.
+    let target = most_common(&card_counts).unwrap_or(Card::Joker);
+    *card_counts.entry(target).or_insert(0) += wild_count;
+
+    let mut sorted_values: Vec<usize> = card_counts.values().copied().collect();
+    sorted_values.sort_by(|a, b| b.cmp(a));
+
+    let tie_break = match rules.tie_break {
+        TieBreak::CardOrder => card_array,
+        TieBreak::GroupedRanks => {
+            let group_size = |card: &Card| card_array.iter().filter(|c| *c == card).count();
+            let mut grouped = card_array;
+            grouped.sort_by_key(|card| Reverse((group_size(card), *card)));
+            grouped
+        }
+    };
+
+    let i = 0;
+    match sorted_values[i] {
+        5 => Hand::FiveOfAKind(tie_break),
+        4 => Hand::FourOfAKind(tie_break),
+        3 => match sorted_values[i + 1] {
+            2 => Hand::FullHouse(tie_break),
+            _ => Hand::ThreeOfAKind(tie_break),
+        },
+        2 => match sorted_values[i + 1] {
+            2 => Hand::TwoPair(tie_break),
+            _ => Hand::OnePair(tie_break),
+        },
+        _ => Hand::HighCard(tie_break),
+    }
+}
+
+fn parse_hands(hands_text: &str) -> Result<Vec<([Card; 5], u64)>> {
+    lines(hands_text)
+        .map(|line| {
+            let parsed_values = line.text.split_whitespace().collect::<Vec<&str>>();
+
+            match parsed_values.as_slice() {
+                [hand, bid] => Ok((
+                    string_to_card_array(&line, hand)?,
+                    parse_number(&line, bid)?,
+                )),
+                _ => Err(line.error(
+                    line.text,
+                    ParseErrorKind::Expected("a hand followed by its bid"),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// The sum of every hand's bid times its rank under `rules`.
+pub fn total_winnings(hands_and_bids: &[([Card; 5], u64)], rules: &Rules) -> Result<u64> {
+    let hands = rank_hands(hands_and_bids, rules);
+
+    hands
+        .iter()
+        // enumerate here to get the rank of each hand (0'th index, see next comment)
+        .enumerate()
+        // + 1 to the rank, because the 0th  index is rank 1
+        .try_fold(0_u64, |acc, val| {
+            let bid = hands_and_bids[val.1 .0].1;
+            u64::try_from(val.0 + 1)
+                .ok()
+                .and_then(|rank| bid.checked_mul(rank))
+                .and_then(|winnings| acc.checked_add(winnings))
+        })
+        .ok_or(Error::Overflow("the total winnings"))
+}
+
+/// Each hand along with its index in `hands_and_bids`, weakest hand first (lowest rank) and
+/// strongest hand last (highest rank).
+fn rank_hands(hands_and_bids: &[([Card; 5], u64)], rules: &Rules) -> Vec<(usize, Hand)> {
+    // keep track of the original index so we can find each hand's bid
+    let mut hands: Vec<(usize, Hand)> = hands_and_bids
+        .iter()
+        .enumerate()
+        .map(|(i, (hand, _))| (i, parse_hand(hand, rules)))
+        .collect();
+
+    hands.sort_by(|a, b| a.1.cmp(&b.1));
+    hands
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// Each hand's cards, reading `J` as a jack, and its bid. Hands are only ranked once we
+    /// know whether `J` is a jack or a joker.
+    type Input = Vec<([Card; 5], u64)>;
+    type PartOne = Result<u64>;
+    type PartTwo = Result<u64>;
+
+    fn parse(hands_text: &str) -> Result<Self::Input> {
+        parse_hands(hands_text)
+    }
+
+    fn part_one(hands: &Self::Input) -> Result<u64> {
+        total_winnings(hands, &Rules::JACKS)
+    }
+
+    fn part_two(hands: &Self::Input) -> Result<u64> {
+        total_winnings(hands, &Rules::JOKERS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn cards(text: &str) -> [Card; 5] {
+        string_to_card_array(&Line { number: 1, text }, text).unwrap()
+    }
+
+    fn hand(text: &str, rules: &Rules) -> Hand {
+        parse_hand(&cards(text), rules)
+    }
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 6440);
+        assert_eq!(Solution::part_two(&input).unwrap(), 5905);
+    }
+
+    #[test]
+    fn winnings_that_overflow_are_an_error() {
+        // the big bid is on the stronger hand, so it is doubled
+        let hands = format!("23456 1\n65432 {}", u64::MAX / 2 + 1);
+        let input = Solution::parse(&hands).unwrap();
+        assert!(matches!(
+            Solution::part_one(&input),
+            Err(Error::Overflow(_))
+        ));
+
+        let hands = format!("23456 1\n65432 {}", u64::MAX / 2);
+        let input = Solution::parse(&hands).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn same_type_is_ordered_card_by_card() {
+        assert!(Hand::FourOfAKind(cards("88788")) < Hand::FourOfAKind(cards("99929")));
+        assert!(Hand::TwoPair(cards("KK677")) > Hand::TwoPair(cards("KTJJT")));
+        assert!(Hand::ThreeOfAKind(cards("T55J5")) < Hand::ThreeOfAKind(cards("QQQJA")));
+    }
+
+    #[test]
+    fn hand_types() {
+        assert!(matches!(hand("AAAAA", &Rules::JACKS), Hand::FiveOfAKind(_)));
+        assert!(matches!(hand("23332", &Rules::JACKS), Hand::FullHouse(_)));
+        assert!(matches!(
+            hand("TTT98", &Rules::JACKS),
+            Hand::ThreeOfAKind(_)
+        ));
+        assert!(matches!(hand("23432", &Rules::JACKS), Hand::TwoPair(_)));
+        assert!(matches!(hand("A23A4", &Rules::JACKS), Hand::OnePair(_)));
+        assert!(matches!(hand("23456", &Rules::JACKS), Hand::HighCard(_)));
+    }
+
+    #[test]
+    fn jokers_join_the_largest_group() {
+        assert!(matches!(
+            hand("QJJQ2", &Rules::JOKERS),
+            Hand::FourOfAKind(_)
+        ));
+        assert!(matches!(hand("J2345", &Rules::JOKERS), Hand::OnePair(_)));
+        assert!(matches!(
+            hand("JJJJ2", &Rules::JOKERS),
+            Hand::FiveOfAKind(_)
+        ));
+        assert!(matches!(hand("2233J", &Rules::JOKERS), Hand::FullHouse(_)));
+    }
+
+    #[test]
+    fn all_jokers_is_the_weakest_five_of_a_kind() {
+        assert!(matches!(hand("JJJJJ", &Rules::JACKS), Hand::FiveOfAKind(_)));
+        assert!(matches!(
+            hand("JJJJJ", &Rules::JOKERS),
+            Hand::FiveOfAKind(_)
+        ));
+        assert!(hand("JJJJJ", &Rules::JOKERS) < hand("22222", &Rules::JOKERS));
+        assert!(hand("JJJJJ", &Rules::JACKS) > hand("TTTTT", &Rules::JACKS));
+    }
+
+    #[test]
+    fn jokers_are_weakest_when_breaking_ties() {
+        assert!(hand("JKKK2", &Rules::JOKERS) < hand("QQQQ2", &Rules::JOKERS));
+        assert!(hand("JKKK2", &Rules::JACKS) < hand("QQQQ2", &Rules::JACKS));
+    }
+
+    #[test]
+    fn wildcards_can_be_any_card_and_keep_their_rank() {
+        let twos = Rules {
+            wild: Some(Card::Two),
+            ..Rules::JOKERS
+        };
+        assert!(matches!(hand("2KKK3", &twos), Hand::FourOfAKind(_)));
+        assert!(matches!(hand("JKKK3", &twos), Hand::ThreeOfAKind(_)));
+
+        let natural = Rules {
+            wild_rank: WildRank::Natural,
+            ..Rules::JOKERS
+        };
+        assert!(hand("JKKK2", &natural) > hand("TTTT2", &natural));
+        assert!(hand("JKKK2", &Rules::JOKERS) < hand("TTTT2", &Rules::JOKERS));
+    }
+
+    #[test]
+    fn grouped_ranks_compare_the_largest_group_first() {
+        let poker = Rules {
+            tie_break: TieBreak::GroupedRanks,
+            ..Rules::JACKS
+        };
+        assert!(hand("22AAA", &poker) > hand("KKK22", &poker));
+        assert!(hand("22AAA", &Rules::JACKS) < hand("KKK22", &Rules::JACKS));
+        assert!(hand("3A3K2", &poker) > hand("A2K2Q", &poker));
+    }
+}
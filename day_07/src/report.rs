@@ -0,0 +1,131 @@
+use crate::{joker_target, parse_hand, rank_hands, Card, Rules};
+use std::fmt;
+
+/// How one hand was ranked, for working out why a total is wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand {
+    pub cards: [Card; 5],
+    pub bid: u64,
+    /// The hand's type with every card taken at face value.
+    pub dealt: &'static str,
+    /// The hand's type once its wildcards join `joker_target`.
+    pub promoted: &'static str,
+    pub joker_target: Option<Card>,
+    /// 1 for the weakest hand.
+    pub rank: usize,
+    /// The bid times the rank, which always fits in a `u128`.
+    pub winnings: u128,
+}
+
+impl RankedHand {
+    /// The cards as they were written, e.g. `KTJJT`.
+    pub fn card_text(&self) -> String {
+        self.cards.iter().map(|card| card.to_char()).collect()
+    }
+
+    fn target_text(&self) -> String {
+        self.joker_target
+            .map_or(String::new(), |card| card.to_char().to_string())
+    }
+}
+
+/// Every hand with how it was ranked under `rules`, weakest first.
+pub fn explain(hands_and_bids: &[([Card; 5], u64)], rules: &Rules) -> Vec<RankedHand> {
+    let face_value = Rules {
+        wild: None,
+        ..*rules
+    };
+
+    rank_hands(hands_and_bids, rules)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (index, hand))| {
+            let (cards, bid) = hands_and_bids[index];
+
+            RankedHand {
+                cards,
+                bid,
+                dealt: parse_hand(&cards, &face_value).name(),
+                promoted: hand.name(),
+                joker_target: joker_target(&cards, rules),
+                rank: i + 1,
+                winnings: bid as u128 * (i + 1) as u128,
+            }
+        })
+        .collect()
+}
+
+/// One row per hand, with a header.
+pub fn to_csv(hands: &[RankedHand]) -> String {
+    let mut csv = "rank,cards,bid,dealt,promoted,joker_target,winnings\n".to_string();
+
+    for hand in hands {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            hand.rank,
+            hand.card_text(),
+            hand.bid,
+            hand.dealt,
+            hand.promoted,
+            hand.target_text(),
+            hand.winnings
+        ));
+    }
+
+    csv
+}
+
+/// Prints the hand on one line, e.g. `5  KTJJT  220  two pair -> four of a kind (T)  1100`.
+impl fmt::Display for RankedHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>4}  {}  {:>4}  {}",
+            self.rank,
+            self.card_text(),
+            self.bid,
+            self.dealt
+        )?;
+
+        if let Some(target) = self.joker_target {
+            write!(f, " -> {} ({})", self.promoted, target.to_char())?;
+        }
+
+        write!(f, "  {}", self.winnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Solution, Solver};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn explains_the_example_with_jokers() {
+        let hands = explain(&Solution::parse(EXAMPLE).unwrap(), &Rules::JOKERS);
+
+        assert_eq!(hands.iter().map(|hand| hand.winnings).sum::<u128>(), 5905);
+
+        let strongest = hands.last().unwrap();
+        assert_eq!(strongest.card_text(), "KTJJT");
+        assert_eq!(
+            (strongest.dealt, strongest.promoted, strongest.joker_target),
+            ("two pair", "four of a kind", Some(Card::Ten))
+        );
+        assert_eq!(
+            strongest.to_string(),
+            "   5  KTJJT   220  two pair -> four of a kind (T)  1100"
+        );
+
+        let csv = to_csv(&hands);
+        assert_eq!(
+            csv.lines().take(2).collect::<Vec<_>>(),
+            vec![
+                "rank,cards,bid,dealt,promoted,joker_target,winnings",
+                "1,32T3K,765,one pair,one pair,,765",
+            ]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{parse_hand, Card, Hand, Rules};
+use std::fmt;
+
+/// A hand whose type under the wildcard heuristic in `parse_hand` is not the best type any
+/// substitution of its wildcards makes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub cards: [Card; 5],
+    pub heuristic: &'static str,
+    pub best: &'static str,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: String = self.cards.iter().map(|card| card.to_char()).collect();
+        write!(
+            f,
+            "{}: the heuristic makes {} but {} is possible",
+            cards, self.heuristic, self.best
+        )
+    }
+}
+
+/// Every way to pick `count` cards from the deck when order doesn't matter, each as a sorted
+/// list.
+fn multisets(count: usize) -> Vec<Vec<Card>> {
+    (0..count).fold(vec![Vec::new()], |picks, _| {
+        picks
+            .into_iter()
+            .flat_map(|pick: Vec<Card>| {
+                let weakest = pick.last().copied();
+                Card::DECK
+                    .into_iter()
+                    .filter(move |card| weakest.is_none_or(|weakest| *card >= weakest))
+                    .map(move |card| [pick.clone(), vec![card]].concat())
+            })
+            .collect()
+    })
+}
+
+/// The best hand any substitution of the wildcards in `cards` makes, found by trying every
+/// combination of cards in the wild positions. The order of the cards doesn't change a hand's
+/// type, so neither does which wild position gets which card.
+fn best_substitution(cards: &[Card; 5], rules: &Rules) -> Hand {
+    let plain = Rules {
+        wild: None,
+        ..*rules
+    };
+    let wild_positions: Vec<usize> = (0..5).filter(|i| rules.is_wild(cards[*i])).collect();
+
+    multisets(wild_positions.len())
+        .into_iter()
+        .map(|substitution| {
+            let mut substituted = *cards;
+            for (position, card) in wild_positions.iter().zip(substitution) {
+                substituted[*position] = card;
+            }
+            parse_hand(&substituted, &plain)
+        })
+        .max_by_key(Hand::strength)
+        .expect("there is always at least one substitution")
+}
+
+/// Checks the wildcard heuristic against a brute-force search over every possible hand,
+/// returning each hand where the two disagree on its type. Hands are only tried in one
+/// order, since the order of the cards doesn't change a hand's type.
+pub fn verify_wildcards(rules: &Rules) -> Vec<Mismatch> {
+    multisets(5)
+        .into_iter()
+        .filter_map(|pick| {
+            let cards: [Card; 5] = pick.try_into().expect("picked 5 cards");
+
+            let heuristic = parse_hand(&cards, rules);
+            let best = best_substitution(&cards, rules);
+
+            (heuristic.strength() != best.strength()).then(|| Mismatch {
+                cards,
+                heuristic: heuristic.name(),
+                best: best.name(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joker_heuristic_matches_brute_force() {
+        assert_eq!(verify_wildcards(&Rules::JOKERS), vec![]);
+
+        let twos = Rules {
+            wild: Some(Card::Two),
+            ..Rules::JOKERS
+        };
+        assert_eq!(verify_wildcards(&twos), vec![]);
+    }
+
+    #[test]
+    fn every_hand_is_tried_once() {
+        // 13 multichoose 5
+        assert_eq!(multisets(5).len(), 6188);
+        assert_eq!(multisets(0), vec![Vec::<Card>::new()]);
+    }
+
+    #[test]
+    fn brute_force_only_substitutes_wildcards() {
+        let cards = [Card::Jack, Card::Two, Card::Two, Card::Three, Card::Four];
+        assert_eq!(
+            best_substitution(&cards, &Rules::JOKERS).name(),
+            "three of a kind"
+        );
+        assert_eq!(best_substitution(&cards, &Rules::JACKS).name(), "one pair");
+    }
+}
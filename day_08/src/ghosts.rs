@@ -0,0 +1,232 @@
+use crate::Maze;
+use aoc_common::{Error, Result};
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Where a ghost's walk from one start ends up. Its state is the node it is on and how far
+/// through the instructions it is, so once a state repeats the walk repeats forever: after
+/// `prefix` steps it loops every `cycle` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub start: u32,
+    pub prefix: u64,
+    pub cycle: u64,
+    /// Every step before the walk first repeats a state at which the ghost is on an end node.
+    /// Those from `prefix` on come round again every `cycle` steps.
+    pub hits: Vec<u64>,
+}
+
+/// The steps at which one ghost is on an end node: `first`, and then every `period` steps
+/// after it, or only `first` if `period` is 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Arrivals {
+    first: u128,
+    period: u128,
+}
+
+const ARRIVAL_OVERFLOWED: Error = Error::Overflow("the steps until every ghost is on an end node");
+
+/// Walks from `start` until the (node, instruction) state repeats, noting every step at which
+/// `is_end` holds for the node reached. A maze with no instructions has no walks.
+pub fn walk(maze: &Maze, start: u32, is_end: &[bool]) -> Option<Walk> {
+    let directions = maze.directions();
+    // the step each state was first reached at, holding only the states this walk visits
+    let mut first_seen: HashMap<(u32, usize), u64> = HashMap::new();
+    let mut hits = Vec::new();
+    let mut node = start;
+
+    for (step, (instruction, direction)) in directions.iter().enumerate().cycle().enumerate() {
+        let step = step as u64;
+
+        match first_seen.entry((node, instruction)) {
+            Entry::Occupied(seen) => {
+                return Some(Walk {
+                    start,
+                    prefix: *seen.get(),
+                    cycle: step - seen.get(),
+                    hits,
+                });
+            }
+            Entry::Vacant(unseen) => {
+                unseen.insert(step);
+            }
+        }
+
+        if is_end[node as usize] {
+            hits.push(step);
+        }
+        node = maze.next(node, *direction);
+    }
+
+    None
+}
+
+impl Walk {
+    /// Whether the ghost is on an end node after `step` steps.
+    fn on_end(&self, step: u64) -> bool {
+        let step = if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.cycle
+        };
+        self.hits.binary_search(&step).is_ok()
+    }
+
+    /// The end-node arrivals that keep coming round once the walk is in its loop.
+    fn looping_arrivals(&self) -> Vec<Arrivals> {
+        self.hits
+            .iter()
+            .filter(|hit| **hit >= self.prefix)
+            .map(|hit| Arrivals {
+                first: *hit as u128,
+                period: self.cycle as u128,
+            })
+            .collect()
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The `x` for which `a * x ≡ 1 (mod modulus)`, found with the extended Euclidean algorithm.
+/// `a` and `modulus` must be coprime and fit in a `u64`.
+fn mod_inverse(a: u128, modulus: u128) -> u128 {
+    let (mut old_r, mut r) = (a as i128, modulus as i128);
+    let (mut old_s, mut s) = (1_i128, 0_i128);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    old_s.rem_euclid(modulus as i128) as u128
+}
+
+/// The steps at which both `a` and `b` have a ghost on an end node, found with the
+/// generalised Chinese remainder theorem. `b`'s period must fit in a `u64`.
+fn intersect(a: Arrivals, b: Arrivals) -> Result<Option<Arrivals>> {
+    let lands = |arrivals: Arrivals, step: u128| {
+        step >= arrivals.first && (step - arrivals.first).is_multiple_of(arrivals.period)
+    };
+
+    if a.period == 0 {
+        return Ok(lands(b, a.first).then_some(a));
+    }
+    if b.period == 0 {
+        return Ok(lands(a, b.first).then_some(b));
+    }
+
+    // looking for a.first + a.period * k ≡ b.first (mod b.period), which has a solution only
+    // when the gcd of the periods divides the gap between the two
+    let common = gcd(a.period, b.period);
+    let gap = (b.first % b.period + b.period - a.first % b.period) % b.period;
+    if !gap.is_multiple_of(common) {
+        return Ok(None);
+    }
+
+    // both are below b.period / common, so their product fits
+    let modulus = b.period / common;
+    let k = (gap / common) * mod_inverse(a.period / common % modulus, modulus) % modulus;
+
+    let period = (a.period / common)
+        .checked_mul(b.period)
+        .ok_or(ARRIVAL_OVERFLOWED)?;
+    // the first step from a.first on where both line up, moved on to b's first arrival
+    let mut first = a
+        .period
+        .checked_mul(k)
+        .and_then(|offset| offset.checked_add(a.first))
+        .ok_or(ARRIVAL_OVERFLOWED)?;
+    if first < b.first {
+        first = (b.first - first)
+            .div_ceil(period)
+            .checked_mul(period)
+            .and_then(|offset| offset.checked_add(first))
+            .ok_or(ARRIVAL_OVERFLOWED)?;
+    }
+
+    Ok(Some(Arrivals { first, period }))
+}
+
+/// The first step at which every ghost is on an end node at once, or `None` if that never
+/// happens.
+pub fn first_simultaneous(walks: &[Walk]) -> Result<Option<u64>> {
+    // every ghost is in its loop by `settled`, so any earlier meeting is found one step at a time
+    let settled = walks.iter().map(|walk| walk.prefix).max().unwrap_or(0)
+        + walks.iter().map(|walk| walk.cycle).max().unwrap_or(0);
+    if let Some(step) = (0..settled).find(|step| walks.iter().all(|walk| walk.on_end(*step))) {
+        return Ok(Some(step));
+    }
+
+    // from there on only the looping arrivals matter
+    let arrivals: Vec<Vec<Arrivals>> = walks.iter().map(Walk::looping_arrivals).collect();
+    let mut best = None;
+    narrow(
+        Arrivals {
+            first: settled as u128,
+            period: 1,
+        },
+        &arrivals,
+        &mut best,
+    )?;
+
+    best.map(|first| u64::try_from(first).map_err(|_| ARRIVAL_OVERFLOWED))
+        .transpose()
+}
+
+/// Narrows `candidate` down by each ghost's arrivals in turn, depth first, keeping the earliest
+/// step that suits every ghost in `best`. Narrowing never moves a candidate earlier, so one that
+/// is already no earlier than `best` is dropped rather than followed.
+fn narrow(candidate: Arrivals, ghosts: &[Vec<Arrivals>], best: &mut Option<u128>) -> Result<()> {
+    if best.is_some_and(|best| candidate.first >= best) {
+        return Ok(());
+    }
+
+    match ghosts.split_first() {
+        None => *best = Some(candidate.first),
+        Some((arrivals, rest)) => {
+            for arrivals in arrivals {
+                if let Some(next) = intersect(candidate, *arrivals)? {
+                    narrow(next, rest, best)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arrivals(first: u128, period: u128) -> Arrivals {
+        Arrivals { first, period }
+    }
+
+    #[test]
+    fn intersects_progressions() {
+        // 2, 5, 8, ... and 4, 8, 12, ...
+        assert_eq!(
+            intersect(arrivals(2, 3), arrivals(4, 4)).unwrap(),
+            Some(arrivals(8, 12))
+        );
+        // the periods share a factor of 2 that the gap doesn't have
+        assert_eq!(intersect(arrivals(0, 2), arrivals(1, 4)).unwrap(), None);
+        // 1, 7, 13, ... and 4, 7, 10, ... already line up at 7 but not before b starts
+        assert_eq!(
+            intersect(arrivals(1, 6), arrivals(7, 3)).unwrap(),
+            Some(arrivals(7, 6))
+        );
+        assert_eq!(
+            intersect(arrivals(9, 0), arrivals(1, 4)).unwrap(),
+            Some(arrivals(9, 0))
+        );
+        assert_eq!(intersect(arrivals(1, 4), arrivals(2, 0)).unwrap(), None);
+    }
+}
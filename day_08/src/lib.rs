@@ -0,0 +1,553 @@
+use aoc_common::parse::{lines, Line};
+use aoc_common::{reject_issues, Error, ParseErrorKind, Result, Solver};
+use std::collections::HashMap;
+use std::fmt;
+
+mod ghosts;
+
+pub use ghosts::{first_simultaneous, walk, Walk};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/maze.txt");
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+/// Reads `(LEFT, RIGHT)` into the labels of the two nodes.
+fn make_node<'a>(line: &Line, left_and_right: &'a str) -> Result<[&'a str; 2]> {
+    let left_and_right_parsed: Vec<&str> = left_and_right
+        .strip_prefix('(')
+        .ok_or_else(|| line.error(left_and_right, ParseErrorKind::Expected("`(`")))?
+        .strip_suffix(')')
+        .ok_or_else(|| line.error_at_end(ParseErrorKind::Expected("`)`")))?
+        .split(',')
+        .collect();
+
+    match left_and_right_parsed.as_slice() {
+        [left, right] => Ok([left.trim(), right.trim()]),
+        _ => Err(line.error(
+            left_and_right,
+            ParseErrorKind::Expected("two nodes in the form `(LEFT, RIGHT)`"),
+        )),
+    }
+}
+
+/// The left/right instructions and the network of nodes. Each node's label is interned to a
+/// dense id, numbered in the order the nodes are defined, so a step is a lookup in `edges`
+/// rather than a hash of the label.
+#[derive(Debug)]
+pub struct Maze {
+    directions: Vec<Direction>,
+    names: Vec<String>,
+    ids: HashMap<String, u32>,
+    /// The left and right neighbour of every node, indexed by id and then by [`Direction`]. A
+    /// node that is never defined leads back to itself.
+    edges: Vec<[u32; 2]>,
+    /// The line each node was defined on, for reporting problems with it.
+    defined_on: Vec<Option<usize>>,
+    /// The first line each node was named on.
+    named_on: Vec<usize>,
+    /// Every definition of a node that was already defined, as its line and the node's id.
+    redefined: Vec<(usize, u32)>,
+}
+
+impl Maze {
+    /// Reads a maze without checking it; see [`Maze::validate`].
+    pub fn parse(maze: &str) -> Result<Self> {
+        parse_maze(maze)
+    }
+
+    /// Every problem with the maze's structure that would stop a walk through it, or make it
+    /// ambiguous, in line order. Whether a start can reach its end only matters to the part
+    /// walking from it, so each part checks that for itself.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if self.directions.is_empty() {
+            issues.push(Issue {
+                line: 1,
+                kind: IssueKind::NoInstructions,
+            });
+        }
+        for node in self.nodes() {
+            if self.defined_on[node as usize].is_none() {
+                issues.push(Issue {
+                    line: self.named_on[node as usize],
+                    kind: IssueKind::UndefinedNode(self.name(node).to_string()),
+                });
+            }
+        }
+        for (line, node) in &self.redefined {
+            issues.push(Issue {
+                line: *line,
+                kind: IssueKind::Redefined {
+                    name: self.name(*node).to_string(),
+                    first: self.defined_on[*node as usize].unwrap_or(*line),
+                },
+            });
+        }
+
+        issues.sort_by_key(|issue| issue.line);
+        issues
+    }
+
+    /// Whether each node can be reached from `from` in any number of steps, going either way
+    /// at every node.
+    pub fn reachable(&self, from: u32) -> Vec<bool> {
+        let mut reached = vec![false; self.len()];
+        let mut stack = vec![from];
+
+        while let Some(node) = stack.pop() {
+            if !reached[node as usize] {
+                reached[node as usize] = true;
+                stack.extend(self.edges[node as usize]);
+            }
+        }
+
+        reached
+    }
+
+    /// Whether some node `is_end` accepts can be reached from `from`.
+    fn reaches(&self, from: u32, is_end: impl Fn(u32) -> bool) -> bool {
+        self.reachable(from)
+            .into_iter()
+            .zip(self.nodes())
+            .any(|(reached, node)| reached && is_end(node))
+    }
+
+    fn reaches_zzz(&self, from: u32) -> bool {
+        self.id("ZZZ")
+            .is_some_and(|end| self.reachable(from)[end as usize])
+    }
+
+    fn is_ghost_end(&self, node: u32) -> bool {
+        self.name(node).ends_with('Z')
+    }
+
+    /// The number of different (node, instruction) states a walk can be in. A walk that hasn't
+    /// reached its end after this many steps is going round a loop that never does.
+    pub fn state_count(&self) -> u64 {
+        (self.len() as u64).saturating_mul(self.directions.len() as u64)
+    }
+
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every node's id.
+    pub fn nodes(&self) -> impl Iterator<Item = u32> {
+        0..self.names.len() as u32
+    }
+
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    /// The node reached by going `direction` from `node`.
+    pub fn next(&self, node: u32, direction: Direction) -> u32 {
+        self.edges[node as usize][direction as usize]
+    }
+}
+
+fn parse_maze(maze: &str) -> Result<Maze> {
+    let mut maze_lines = lines(maze);
+    let directions = maze_lines
+        .next()
+        .ok_or_else(|| Error::Input("the maze is empty".to_string()))?;
+
+    let directions: Vec<Direction> = directions
+        .text
+        .char_indices()
+        .map(|(i, c)| match c {
+            'R' => Ok(Direction::Right),
+            'L' => Ok(Direction::Left),
+            _ => Err(directions.error(
+                &directions.text[i..i + c.len_utf8()],
+                ParseErrorKind::Expected("`L` or `R`"),
+            )),
+        })
+        .collect::<Result<Vec<Direction>>>()?;
+
+    let mut names: Vec<String> = Vec::new();
+    let mut ids: HashMap<String, u32> = HashMap::new();
+    let mut named_on = Vec::new();
+    let mut definitions = Vec::new();
+
+    let mut intern = |label: &str, line: usize| {
+        *ids.entry(label.to_string()).or_insert_with(|| {
+            names.push(label.to_string());
+            named_on.push(line);
+            names.len() as u32 - 1
+        })
+    };
+
+    for line in maze_lines.filter(|line| !line.text.is_empty()) {
+        let (label, left_and_right) = line
+            .text
+            .split_once(" = ")
+            .ok_or_else(|| line.error_at_end(ParseErrorKind::Expected("` = `")))?;
+        let children = make_node(&line, left_and_right.trim())?;
+
+        // a label defined twice keeps its first definition; validate reports the others
+        let id = intern(label.trim(), line.number);
+        definitions.push((line.number, id, children));
+    }
+
+    // children are interned after every definition, so defined nodes come first
+    let definitions: Vec<(usize, u32, [u32; 2])> = definitions
+        .into_iter()
+        .map(|(line, id, children)| (line, id, children.map(|child| intern(child, line))))
+        .collect();
+
+    let mut edges: Vec<[u32; 2]> = (0..names.len() as u32).map(|id| [id; 2]).collect();
+    let mut defined_on = vec![None; names.len()];
+    let mut redefined = Vec::new();
+    for (line, id, children) in definitions {
+        if defined_on[id as usize].is_some() {
+            redefined.push((line, id));
+            continue;
+        }
+        edges[id as usize] = children;
+        defined_on[id as usize] = Some(line);
+    }
+
+    Ok(Maze {
+        directions,
+        names,
+        ids,
+        edges,
+        defined_on,
+        named_on,
+        redefined,
+    })
+}
+
+/// The kinds of trouble [`Maze::validate`] looks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The first line has no `L` or `R` instructions.
+    NoInstructions,
+    /// A node is named as a neighbour but never given neighbours of its own.
+    UndefinedNode(String),
+    /// A node is defined again after its `first` definition.
+    Redefined { name: String, first: usize },
+}
+
+/// One thing wrong with a maze, and the line to look at to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: usize,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            IssueKind::NoInstructions => write!(f, "there are no instructions to follow"),
+            IssueKind::UndefinedNode(name) => write!(f, "node `{}` is never defined", name),
+            IssueKind::Redefined { name, first } => {
+                write!(f, "node `{}` is defined again after line {}", name, first)
+            }
+        }
+    }
+}
+
+fn no_instructions() -> Error {
+    Error::Input("there are no instructions to follow".to_string())
+}
+
+/// Follows the instructions from `start`, repeating them as often as needed, and counts the
+/// steps until a node `is_end` accepts. Gives up with an error after `budget` steps.
+fn steps_to_end(maze: &Maze, start: u32, is_end: impl Fn(u32) -> bool, budget: u64) -> Result<u64> {
+    let mut curr_node = start;
+    let mut directions = maze.directions.iter().cycle();
+
+    for num_steps in 0..=budget {
+        if is_end(curr_node) {
+            return Ok(num_steps);
+        }
+
+        curr_node = maze.next(curr_node, *directions.next().ok_or_else(no_instructions)?);
+    }
+
+    Err(Error::Input(format!(
+        "gave up walking from `{}` after {} steps",
+        maze.name(start),
+        budget
+    )))
+}
+
+fn part_one(maze: &Maze) -> Result<u64> {
+    let start = maze
+        .id("AAA")
+        .ok_or_else(|| Error::Input("there is no node `AAA` to start from".to_string()))?;
+    if !maze.reaches_zzz(start) {
+        return Err(Error::Input(
+            "`ZZZ` cannot be reached from `AAA`".to_string(),
+        ));
+    }
+    let end = maze.id("ZZZ").expect("ZZZ is reachable, so it exists");
+
+    steps_to_end(maze, start, |node| node == end, maze.state_count())
+}
+
+fn part_two(maze: &Maze) -> Result<u64> {
+    let is_end: Vec<bool> = maze.nodes().map(|node| maze.is_ghost_end(node)).collect();
+
+    let walks: Vec<Walk> = maze
+        .nodes()
+        .filter(|node| maze.name(*node).ends_with('A'))
+        .map(|node| {
+            if maze.reaches(node, |end| is_end[end as usize]) {
+                walk(maze, node, &is_end).ok_or_else(no_instructions)
+            } else {
+                Err(Error::Input(format!(
+                    "no node ending in `Z` can be reached from `{}`",
+                    maze.name(node)
+                )))
+            }
+        })
+        .collect::<Result<_>>()?;
+    if walks.is_empty() {
+        return Err(Error::Input(
+            "no nodes end in `A`, so there are no ghosts to follow".to_string(),
+        ));
+    }
+
+    first_simultaneous(&walks)?.ok_or_else(|| {
+        Error::Input("the ghosts are never all on nodes ending in `Z` at once".to_string())
+    })
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Maze;
+    type PartOne = Result<u64>;
+    type PartTwo = Result<u64>;
+
+    fn parse(nodes_content: &str) -> Result<Self::Input> {
+        let maze = parse_maze(nodes_content)?;
+        reject_issues("the maze", &maze.validate())?;
+
+        Ok(maze)
+    }
+
+    fn part_one(maze: &Self::Input) -> Result<u64> {
+        part_one(maze)
+    }
+
+    fn part_two(maze: &Self::Input) -> Result<u64> {
+        part_two(maze)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_REPEAT: &str = include_str!("../example_repeat.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("../example_part_two.txt");
+
+    #[test]
+    fn part_one_examples() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 2);
+
+        // the instructions repeat until ZZZ is reached
+        let input = Solution::parse(EXAMPLE_REPEAT).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Solution::parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Solution::part_two(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn ghosts_that_loop_after_a_prefix() {
+        // the first ghost reaches a Z node at 2, 5, 8, ... and the second at 4, 8, 12, ..., so
+        // the lcm of the first arrivals, 4, is too early
+        let maze = Solution::parse(
+            "L\n\n\
+             11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (11D, 11D)\n\
+             11D = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22D, 22D)\n\
+             22D = (22Z, 22Z)\n22Z = (22B, 22B)",
+        )
+        .unwrap();
+        assert_eq!(Solution::part_two(&maze).unwrap(), 8);
+
+        let first = walk(&maze, 0, &[false, false, true, false, false]).unwrap();
+        assert_eq!((first.prefix, first.cycle, first.hits), (2, 3, vec![2]));
+    }
+
+    #[test]
+    fn ghosts_that_never_line_up() {
+        // one ghost is only ever on 11Z at even steps and the other at odd steps
+        let maze = Solution::parse(
+            "L\n\n\
+             11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n\
+             22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22Z, 22Z)",
+        )
+        .unwrap();
+        assert!(matches!(
+            Solution::part_two(&maze),
+            Err(Error::Input(message)) if message.contains("never")
+        ));
+    }
+
+    #[test]
+    fn many_ghosts_meeting_early_are_found_without_combining_every_arrival() {
+        // each ghost loops round a cycle made only of Z nodes, so they all meet at step 1
+        let mut maze = String::from("L\n\n");
+        for (ghost, length) in [7, 11, 13, 17, 19, 23, 29].into_iter().enumerate() {
+            maze += &format!("G{}A = (G{}N0Z, G{}N0Z)\n", ghost, ghost, ghost);
+            for node in 0..length {
+                let next = (node + 1) % length;
+                maze += &format!(
+                    "G{}N{}Z = (G{}N{}Z, G{}N{}Z)\n",
+                    ghost, node, ghost, next, ghost, next
+                );
+            }
+        }
+
+        let maze = Solution::parse(&maze).unwrap();
+        assert_eq!(Solution::part_two(&maze).unwrap(), 1);
+    }
+
+    #[test]
+    fn walks_need_instructions() {
+        let maze = Maze::parse("\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)").unwrap();
+        assert_eq!(walk(&maze, 0, &[false, true]), None);
+        assert!(matches!(
+            Solution::part_two(&maze),
+            Err(Error::Input(message)) if message.contains("no instructions")
+        ));
+    }
+
+    #[test]
+    fn ghost_that_only_passes_an_end_once() {
+        let maze = Solution::parse(
+            "LR\n\n\
+             11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n\
+             22A = (22Z, 22Z)\n22Z = (22Z, 22Z)",
+        )
+        .unwrap();
+        assert_eq!(Solution::part_two(&maze).unwrap(), 1);
+    }
+
+    #[test]
+    fn labels_are_interned_in_definition_order() {
+        let maze = Solution::parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(maze.len(), 8);
+        assert_eq!(maze.id("11A"), Some(0));
+        assert_eq!(maze.name(maze.id("XXX").unwrap()), "XXX");
+
+        let start = maze.id("22A").unwrap();
+        let next = maze.next(start, Direction::Left);
+        assert_eq!(maze.name(next), "22B");
+        assert_eq!(maze.name(maze.next(next, Direction::Right)), "22C");
+    }
+
+    #[test]
+    fn every_problem_with_the_maze_is_reported() {
+        let maze = Maze::parse("\n\nAAA = (BBB, ZZZ)\nZZZ = (CCC, BBB)").unwrap();
+        assert_eq!(
+            maze.validate(),
+            vec![
+                Issue {
+                    line: 1,
+                    kind: IssueKind::NoInstructions,
+                },
+                Issue {
+                    line: 3,
+                    kind: IssueKind::UndefinedNode("BBB".to_string()),
+                },
+                Issue {
+                    line: 4,
+                    kind: IssueKind::UndefinedNode("CCC".to_string()),
+                },
+            ]
+        );
+        assert!(matches!(
+            Solution::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)"),
+            Err(Error::Input(message)) if message.contains("line 3: node `BBB` is never defined")
+        ));
+    }
+
+    #[test]
+    fn redefined_nodes_are_reported() {
+        let maze =
+            Maze::parse("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\nAAA = (AAA, AAA)").unwrap();
+        assert_eq!(
+            maze.validate(),
+            vec![Issue {
+                line: 5,
+                kind: IssueKind::Redefined {
+                    name: "AAA".to_string(),
+                    first: 3,
+                },
+            }]
+        );
+        // the first definition is the one that is kept
+        assert_eq!(Solution::part_one(&maze).unwrap(), 1);
+    }
+
+    #[test]
+    fn unreachable_ends_only_stop_the_part_that_needs_them() {
+        // the ghost from 11A is stuck, but part one never follows it
+        let maze = Solution::parse(
+            "L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n11A = (11B, 11B)\n11B = (11B, 11B)",
+        )
+        .unwrap();
+        assert_eq!(Solution::part_one(&maze).unwrap(), 1);
+        assert!(matches!(
+            Solution::part_two(&maze),
+            Err(Error::Input(message)) if message.contains("can be reached from `11A`")
+        ));
+
+        // and part two never walks from AAA to ZZZ
+        let maze =
+            Solution::parse("L\n\nAAA = (AAZ, AAZ)\nAAZ = (AAZ, AAZ)\nZZZ = (AAA, AAA)").unwrap();
+        assert_eq!(Solution::part_two(&maze).unwrap(), 1);
+        assert!(matches!(
+            Solution::part_one(&maze),
+            Err(Error::Input(message)) if message.contains("cannot be reached")
+        ));
+    }
+
+    #[test]
+    fn ends_only_reached_by_untaken_turns_give_up() {
+        // ZZZ is one right turn away, but the instructions only ever go left
+        let maze =
+            Solution::parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert!(matches!(
+            Solution::part_one(&maze),
+            Err(Error::Input(message)) if message.contains("gave up")
+        ));
+    }
+
+    #[test]
+    fn unknown_direction_is_rejected() {
+        assert!(Solution::parse("LRX\n\nAAA = (AAA, AAA)").is_err());
+    }
+}
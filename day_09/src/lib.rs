@@ -0,0 +1,117 @@
+use aoc_common::parse::{lines, parse_numbers};
+use aoc_common::{ParseErrorKind, Result, Solver};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/oasis.txt");
+
+fn extrapolate(nums: &[i64], backward: bool) -> i64 {
+    let mut differences: Vec<Vec<i64>> = Vec::new();
+
+    let mut curr_diffs = nums
+        .windows(2)
+        .map(|val| val[1] - val[0])
+        .collect::<Vec<i64>>();
+
+    differences.push(curr_diffs.clone());
+
+    // a level with a single difference left can't be differenced again, so treat it as
+    // constant, as if the next level were all zeros
+    while curr_diffs.len() > 1 && !curr_diffs.iter().all(|val| *val == 0) {
+        curr_diffs = curr_diffs
+            .windows(2)
+            .map(|val| val[1] - val[0])
+            .collect::<Vec<i64>>();
+
+        differences.push(curr_diffs.clone());
+    }
+
+    if backward {
+        let mut value = 0;
+        for difference in differences.iter().rev() {
+            value = -value + difference[0];
+        }
+
+        nums[0] - value
+    } else {
+        differences.iter().fold(0, |value, difference| {
+            value + difference[difference.len() - 1]
+        }) + nums[nums.len() - 1]
+    }
+}
+
+fn part_one(oasis_history: &[Vec<i64>]) -> i64 {
+    oasis_history
+        .iter()
+        .map(|history| extrapolate(history, false))
+        .sum::<i64>()
+}
+
+fn part_two(oasis_history: &[Vec<i64>]) -> i64 {
+    oasis_history
+        .iter()
+        .map(|history| extrapolate(history, true))
+        .sum::<i64>()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Vec<i64>>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(oasis_contents: &str) -> Result<Self::Input> {
+        lines(oasis_contents)
+            .map(|line| {
+                let history = parse_numbers::<i64>(&line, line.text)?;
+
+                // extrapolating needs at least one difference between two values.
+                if history.len() < 2 {
+                    return Err(line.error(
+                        line.text,
+                        ParseErrorKind::Expected("a history of at least two values"),
+                    ));
+                }
+
+                Ok(history)
+            })
+            .collect()
+    }
+
+    fn part_one(oasis: &Self::Input) -> i64 {
+        part_one(oasis)
+    }
+
+    fn part_two(oasis: &Self::Input) -> i64 {
+        part_two(oasis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), 114);
+        assert_eq!(Solution::part_two(&input), 2);
+    }
+
+    #[test]
+    fn extrapolates_both_ways() {
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], false), 68);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], true), 5);
+        // constant and decreasing histories
+        assert_eq!(extrapolate(&[4, 4, 4], false), 4);
+        assert_eq!(extrapolate(&[-1, -3, -5], false), -7);
+    }
+
+    #[test]
+    fn short_histories_run_out_of_differences() {
+        let input = Solution::parse("1 3\n1 2 4").unwrap();
+        assert_eq!(Solution::part_one(&input), 5 + 7);
+        assert_eq!(Solution::part_two(&input), -1 + 1);
+    }
+}
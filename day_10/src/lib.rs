@@ -0,0 +1,421 @@
+use aoc_common::{Error, Grid, Position, Result, Solver};
+use core::panic;
+use std::{
+    collections::{HashSet, VecDeque},
+    fs,
+};
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/loop.txt");
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, Clone)]
+pub struct Connection {
+    pipe_type: char,
+    inlet: Direction,
+    outlet: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub enum Tile {
+    Pipe(Connection),
+    Ground, // .
+    Start,  // S
+}
+
+fn char_to_tile(c: char) -> Option<Tile> {
+    let tile = match c {
+        '|' => Tile::Pipe(Connection {
+            pipe_type: '|',
+            inlet: Direction::North,
+            outlet: Direction::South,
+        }),
+        '-' => Tile::Pipe(Connection {
+            pipe_type: '-',
+            inlet: Direction::East,
+            outlet: Direction::West,
+        }),
+        'J' => Tile::Pipe(Connection {
+            pipe_type: 'J',
+            inlet: Direction::North,
+            outlet: Direction::West,
+        }),
+        'L' => Tile::Pipe(Connection {
+            pipe_type: 'L',
+            inlet: Direction::North,
+            outlet: Direction::East,
+        }),
+        'F' => Tile::Pipe(Connection {
+            pipe_type: 'F',
+            inlet: Direction::South,
+            outlet: Direction::East,
+        }),
+        '7' => Tile::Pipe(Connection {
+            pipe_type: '7',
+            inlet: Direction::South,
+            outlet: Direction::West,
+        }),
+        '.' => Tile::Ground,
+        'S' => Tile::Start,
+        _ => return None,
+    };
+
+    Some(tile)
+}
+
+/// The grid of tiles, and the position of the `S` start tile.
+pub type PipeMap = (Grid<Tile>, Position);
+
+fn parse_loop(s: &str) -> Result<PipeMap> {
+    let tiles = Grid::parse(s, "a pipe, `.` or `S`", char_to_tile)?;
+
+    match tiles
+        .positions()
+        .find(|position| matches!(tiles[*position], Tile::Start))
+    {
+        Some(start) => Ok((tiles, start)),
+        None => Err(Error::Input("there is no start tile `S`".to_string())),
+    }
+}
+
+#[allow(dead_code)]
+fn flood_fill(start: Position, walls: &HashSet<Position>, tiles: &Grid<Tile>) {
+    // implement BFS
+    // EDIT: This won't work as is, because the start position is highly dependent and requires
+    // more additional processing of the grid.
+    let mut queue: VecDeque<Position> = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut enclosed_tiles = 0;
+    queue.push_back(start);
+
+    while !queue.is_empty() {
+        let curr = queue.pop_front().unwrap();
+
+        if walls.contains(&curr) || visited.contains(&curr) {
+            continue;
+        }
+
+        visited.insert(curr);
+        enclosed_tiles += 1;
+
+        queue.extend(tiles.neighbours4(curr));
+    }
+
+    println!("Num enclosed tiles: {}", enclosed_tiles);
+}
+
+/// The position as it would appear in the input, for error messages.
+fn describe((row, col): Position) -> String {
+    format!("line {}, column {}", row + 1, col + 1)
+}
+
+impl Direction {
+    /// The (row, col) offset of one step this way.
+    fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::South => (1, 0),
+            Direction::East => (0, 1),
+            Direction::West => (0, -1),
+        }
+    }
+
+    fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+impl Connection {
+    fn connects(&self, direction: Direction) -> bool {
+        self.inlet == direction || self.outlet == direction
+    }
+}
+
+/// Works out which pipe S is, along with the first pipe of the loop after it and the direction
+/// that pipe is entered from.
+fn get_start_pipe(
+    start: Position,
+    tiles: &Grid<Tile>,
+) -> Result<(Connection, Position, Direction)> {
+    // Since we don't know what kind of pipe S is, we need to first figure out what it is by
+    // looking at all neighbourhood pipes (up, down, left, right), and seeing which ones connect
+    // to the S tile. Neighbours off the edge of the grid are skipped.
+    let connected: Vec<(Direction, Position)> = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ]
+    .into_iter()
+    .filter_map(|direction| {
+        let position = tiles.offset(start, direction.offset())?;
+        match &tiles[position] {
+            Tile::Pipe(pipe) if pipe.connects(direction.opposite()) => Some((direction, position)),
+            _ => None,
+        }
+    })
+    .collect();
+
+    let directions: Vec<Direction> = connected.iter().map(|(direction, _)| *direction).collect();
+    let start_pipe = match directions.as_slice() {
+        [Direction::North, Direction::South] => ('|', Direction::North, Direction::South),
+        [Direction::North, Direction::East] => ('L', Direction::North, Direction::East),
+        [Direction::North, Direction::West] => ('J', Direction::North, Direction::West),
+        [Direction::South, Direction::East] => ('F', Direction::South, Direction::East),
+        [Direction::South, Direction::West] => ('7', Direction::South, Direction::West),
+        [Direction::East, Direction::West] => ('-', Direction::East, Direction::West),
+        _ => {
+            return Err(Error::Input(format!(
+                "the start tile `S` at {} connects to {} pipes instead of 2",
+                describe(start),
+                connected.len()
+            )))
+        }
+    };
+
+    let (pipe_type, inlet, outlet) = start_pipe;
+    let (direction, first) = connected[0];
+
+    Ok((
+        Connection {
+            pipe_type,
+            inlet,
+            outlet,
+        },
+        first,
+        direction.opposite(),
+    ))
+}
+
+fn clean_tiles(start: Connection, walls: &HashSet<Position>, tiles: &Grid<Tile>) -> Grid<Tile> {
+    /* Removes all unused pipes (not part of main loop), and replaces S with its actual pipe. */
+    Grid::from_fn(tiles.height(), tiles.width(), |position| {
+        match &tiles[position] {
+            Tile::Start => Tile::Pipe(Connection {
+                pipe_type: start.pipe_type,
+                inlet: start.inlet,
+                outlet: start.outlet,
+            }),
+            tile @ Tile::Pipe(_) if walls.contains(&position) => tile.clone(),
+            _ => Tile::Ground,
+        }
+    })
+}
+
+fn scan_line(tiles: Grid<Tile>) -> usize {
+    let mut is_in_loop = false;
+    let mut opening_pipe = '-';
+
+    tiles
+        .into_iter()
+        .filter(|p| match p {
+            Tile::Ground => is_in_loop,
+            Tile::Pipe(conn) => {
+                if conn.pipe_type == '|' {
+                    is_in_loop = !is_in_loop;
+                } else if conn.pipe_type == 'L' || conn.pipe_type == 'F' {
+                    // "start" pipe, so we set the opening_pipe variable
+                    opening_pipe = conn.pipe_type;
+                } else if conn.pipe_type == 'J' {
+                    if opening_pipe == 'F' {
+                        is_in_loop = !is_in_loop;
+                        opening_pipe = '-';
+                    }
+                } else if conn.pipe_type == '7' && opening_pipe == 'L' {
+                    is_in_loop = !is_in_loop;
+                    opening_pipe = '-';
+                }
+                false
+            }
+            _ => false,
+        })
+        .count()
+}
+
+fn visualize_walls(walls: &HashSet<Position>, tiles: &Grid<Tile>) {
+    let mut array = Grid::new(tiles.height(), tiles.width(), '.');
+
+    for position in walls {
+        // array[*position] = 'X';
+        array[*position] = if let Tile::Pipe(pipe) = &tiles[*position] {
+            pipe.pipe_type
+        } else {
+            panic!("Noooo!");
+        };
+    }
+
+    // Write the array to a text file
+    if fs::write("output.txt", array.to_string()).is_ok() {
+        println!("Visualization written to 'output.txt'");
+    } else {
+        eprintln!("Error creating/opening the file.");
+    }
+}
+
+/// Follows the loop from S back round to it, returning the pipe S stands for, every tile of
+/// the loop and its length. Fails if the loop leaves the grid or runs into a tile that doesn't
+/// connect back, rather than walking forever.
+fn walk_loop(
+    tiles: &Grid<Tile>,
+    start: Position,
+) -> Result<(Connection, HashSet<Position>, usize)> {
+    let (start_pipe, mut curr_position, mut from) = get_start_pipe(start, tiles)?;
+
+    let mut walls = HashSet::new();
+    walls.insert(curr_position);
+
+    let mut steps = 1;
+    while curr_position != start {
+        let Tile::Pipe(pipe) = &tiles[curr_position] else {
+            unreachable!("every step is checked to land on a pipe or S")
+        };
+        let to = if from == pipe.inlet {
+            pipe.outlet
+        } else {
+            pipe.inlet
+        };
+
+        let next = tiles.offset(curr_position, to.offset()).ok_or_else(|| {
+            Error::Input(format!(
+                "the pipe at {} leads off the grid",
+                describe(curr_position)
+            ))
+        })?;
+        from = to.opposite();
+
+        match &tiles[next] {
+            Tile::Start => {}
+            Tile::Pipe(pipe) if pipe.connects(from) => {}
+            _ => {
+                return Err(Error::Input(format!(
+                    "the pipe at {} leads into {}, which does not connect back to it",
+                    describe(curr_position),
+                    describe(next)
+                )))
+            }
+        }
+
+        curr_position = next;
+        walls.insert(curr_position);
+        steps += 1;
+    }
+
+    Ok((start_pipe, walls, steps))
+}
+
+/// Writes the main loop, with every other tile blanked out, to `output.txt`.
+pub fn visualize((tiles, start): &PipeMap) -> Result<()> {
+    let (start_pipe, walls, _) = walk_loop(tiles, *start)?;
+
+    let tiles = clean_tiles(start_pipe, &walls, tiles);
+    visualize_walls(&walls, &tiles);
+    Ok(())
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = PipeMap;
+    type PartOne = Result<usize>;
+    type PartTwo = Result<usize>;
+
+    fn parse(loop_contents: &str) -> Result<Self::Input> {
+        parse_loop(loop_contents)
+    }
+
+    fn part_one((tiles, start): &Self::Input) -> Result<usize> {
+        let (_, _, steps) = walk_loop(tiles, *start)?;
+
+        // furthest number of steps is exactly half way away
+        Ok(steps / 2)
+    }
+
+    fn part_two((tiles, start): &Self::Input) -> Result<usize> {
+        let (start_pipe, walls, _) = walk_loop(tiles, *start)?;
+
+        Ok(scan_line(clean_tiles(start_pipe, &walls, tiles)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("../example_part_two.txt");
+    const EXAMPLE_BORDER: &str = include_str!("../example_border.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Solution::parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Solution::part_two(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn start_on_the_left_border() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(input.1, (2, 0));
+        assert_eq!(Solution::part_two(&input).unwrap(), 1);
+    }
+
+    #[test]
+    fn start_on_the_top_border() {
+        let input = Solution::parse(EXAMPLE_BORDER).unwrap();
+        assert_eq!(input.1, (0, 4));
+        assert_eq!(Solution::part_two(&input).unwrap(), 10);
+    }
+
+    #[test]
+    fn start_in_the_corner() {
+        let input = Solution::parse("S7\nLJ").unwrap();
+        assert_eq!(Solution::part_one(&input).unwrap(), 2);
+        assert_eq!(Solution::part_two(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn missing_start_is_rejected() {
+        assert!(Solution::parse("F7\nLJ").is_err());
+    }
+
+    #[test]
+    fn start_without_two_pipes_is_an_error() {
+        let input = Solution::parse("S-.\n...").unwrap();
+        assert!(matches!(
+            Solution::part_one(&input),
+            Err(Error::Input(message)) if message.contains("connects to 1 pipes")
+        ));
+    }
+
+    #[test]
+    fn broken_loop_is_an_error_instead_of_a_hang() {
+        let input = Solution::parse(".....\n.S-7.\n.|.|.\n.L-..\n.....").unwrap();
+        assert!(matches!(
+            Solution::part_two(&input),
+            Err(Error::Input(message)) if message.contains("does not connect back")
+        ));
+
+        // the 7 turns south off the bottom of the grid
+        let input = Solution::parse("S-7\n|.|\nL-7").unwrap();
+        assert!(matches!(
+            Solution::part_one(&input),
+            Err(Error::Input(message)) if message.contains("leads off the grid")
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::{Grid, Result, Solver};
+use itertools::Itertools;
+use std::cmp;
+
+pub const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// The universe with each galaxy numbered from 1 (empty space is 0), followed by the
+/// indices of the rows and the columns that contain no galaxies.
+pub type Universe = (Grid<i32>, Vec<usize>, Vec<usize>);
+
+#[allow(dead_code)]
+fn insert_space(universe: &mut Vec<Vec<i32>>) -> (Vec<usize>, Vec<usize>) {
+    /* This was initially used for part one to expand the matrix to insert additional
+    rows and columns of space. But this has been superseded by a much simpler intuition
+    - see the function shortest_path_with_space_expansion */
+    let mut no_galaxy_row_indices: Vec<usize> = Vec::new();
+    let mut no_galaxy_col_indices: Vec<usize> = Vec::new();
+
+    // get row indexes to insert
+    // need to account for the fact that we will be mutating the universe
+    // (inserting new rows/cols) even as we are looping over the universe matrix.
+    // so when we insert a new row, the index of the next row to insert would be increased by 1.
+    // similarly for the cols.
+    let mut num_rows_inserted = 0;
+    for (idx, row) in universe.iter().enumerate() {
+        if row.iter().all(|i| *i == 0) {
+            no_galaxy_row_indices.push(idx + num_rows_inserted);
+            num_rows_inserted += 1;
+        }
+    }
+
+    for row in no_galaxy_row_indices.iter() {
+        universe.insert(*row, vec![0; universe[0].len()]);
+    }
+
+    // get col indexes to insert
+    let mut num_cols_inserted = 0;
+    for col in 0..universe[0].len() {
+        let mut only_zeroes = true;
+        for row in universe.iter() {
+            if row[col] != 0 {
+                only_zeroes = false;
+                break;
+            }
+        }
+
+        if only_zeroes {
+            no_galaxy_col_indices.push(col + num_cols_inserted);
+            num_cols_inserted += 1;
+        }
+    }
+
+    for col in no_galaxy_col_indices.iter() {
+        for row in universe.iter_mut() {
+            row.insert(*col, 0);
+        }
+    }
+
+    (no_galaxy_row_indices, no_galaxy_col_indices)
+}
+
+fn get_empty_rows(universe: &Grid<i32>) -> Vec<usize> {
+    universe
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|i| *i == 0))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn get_space(universe: &Grid<i32>) -> (Vec<usize>, Vec<usize>) {
+    /* Gets all row and column indices that have **no** galaxies in them. */
+    // the empty columns of the universe are the empty rows of its transpose
+    (
+        get_empty_rows(universe),
+        get_empty_rows(&universe.transpose()),
+    )
+}
+
+fn shortest_path_with_space_expansion(
+    universe: &Grid<i32>,
+    space_row_indices: &[usize],
+    space_col_indices: &[usize],
+    spaces_to_expand: usize,
+) -> usize {
+    // gets the coordinates of all galaxies (non-zero digits)
+    let galaxies: Vec<(i32, usize, usize)> = universe
+        .indexed()
+        .map(|((i, j), galaxy)| (*galaxy, i, j))
+        .filter(|(galaxy, _, _)| *galaxy != 0)
+        .collect();
+
+    /* the intuition here is - for a given pair of galaxies, we want to identify
+    all the row and columns that are going to be expanded. we can check if such a
+    row/column index lies between the pair of galaxies. once we know a certain row/column
+    index is relevant to the pair of galaxies, we expand it mathematically, i.e. the number of
+    such row/cols indices between the pair of galaxies multiplied by the space to be expanded.
+    take that and add it to the total number of steps between the pair. that is the shortest path.
+    */
+    galaxies
+        .iter()
+        .combinations(2)
+        .map(|pair| {
+            let mut num_empty_spaces_in_between = 0;
+
+            num_empty_spaces_in_between += space_col_indices
+                .iter()
+                .filter(|col| {
+                    *col < cmp::max(&pair[0].2, &pair[1].2)
+                        && *col > cmp::min(&pair[0].2, &pair[1].2)
+                })
+                .count();
+
+            num_empty_spaces_in_between += space_row_indices
+                .iter()
+                .filter(|row| {
+                    *row < cmp::max(&pair[0].1, &pair[1].1)
+                        && *row > cmp::min(&pair[0].1, &pair[1].1)
+                })
+                .count();
+
+            pair[0].1.abs_diff(pair[1].1)
+                + pair[0].2.abs_diff(pair[1].2)
+                + (spaces_to_expand * num_empty_spaces_in_between)
+        })
+        .sum::<usize>()
+}
+
+fn parse_universe(universe: &str) -> Result<Universe> {
+    let mut num_galaxies = 0;
+    let parsed_universe = Grid::parse(universe, "`.` or `#`", |c| match c {
+        '#' => {
+            num_galaxies += 1;
+            Some(num_galaxies)
+        }
+        '.' => Some(0),
+        _ => None,
+    })?;
+
+    let (space_row_indices, space_col_indices) = get_space(&parsed_universe);
+
+    Ok((parsed_universe, space_row_indices, space_col_indices))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Universe;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(universe: &str) -> Result<Self::Input> {
+        parse_universe(universe)
+    }
+
+    fn part_one((universe, space_rows, space_cols): &Self::Input) -> usize {
+        shortest_path_with_space_expansion(universe, space_rows, space_cols, 1)
+    }
+
+    fn part_two((universe, space_rows, space_cols): &Self::Input) -> usize {
+        shortest_path_with_space_expansion(universe, space_rows, space_cols, 999999)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let input = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), 374);
+    }
+
+    #[test]
+    fn larger_expansions() {
+        let (universe, space_rows, space_cols) = Solution::parse(EXAMPLE).unwrap();
+
+        // each empty row or column is replaced by 10 (or 100), so 9 (or 99) are added
+        assert_eq!(
+            shortest_path_with_space_expansion(&universe, &space_rows, &space_cols, 9),
+            1030
+        );
+        assert_eq!(
+            shortest_path_with_space_expansion(&universe, &space_rows, &space_cols, 99),
+            8410
+        );
+    }
+
+    #[test]
+    fn empty_rows_and_columns() {
+        let (_, space_rows, space_cols) = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(space_rows, vec![3, 7]);
+        assert_eq!(space_cols, vec![2, 5, 8]);
+    }
+}